@@ -0,0 +1,1939 @@
+#![allow(clippy::missing_errors_doc)]
+#![allow(clippy::missing_panics_doc)]
+
+use anyhow::{Context, Result, bail};
+use phf_codegen::Map;
+use scraper::{ElementRef, Html, Selector};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Debug, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+include!("../../src/models/mod.rs");
+
+pub const DEFAULT_BASE_URL: &str = "https://european-alternatives.eu";
+const DEFAULT_FLAGS_BASE_URL: &str = "https://cdn.european-alternatives.eu";
+const DEFAULT_HTTP_ATTEMPTS: u32 = 3;
+const DEFAULT_HTTP_RETRY_DELAY_MS: u64 = 500;
+const DEFAULT_HTTP_TIMEOUT_SECS: u64 = 30;
+const DEFAULT_SCRAPER_CACHE_MAX_AGE_SECS: u64 = 24 * 60 * 60;
+const PROGRESS_REPORT_INTERVAL: usize = 10;
+
+// ===== HTTP CLIENT =====
+
+struct HttpClient;
+
+static LAST_REQUEST_START: Mutex<Option<Instant>> = Mutex::new(None);
+
+enum RequestOutcome {
+    Success(minreq::Response),
+    Retryable(anyhow::Error),
+    Fatal(anyhow::Error),
+}
+
+impl HttpClient {
+    fn max_attempts() -> u32 {
+        std::env::var("BUILD_HTTP_ATTEMPTS")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .filter(|&attempts| attempts > 0)
+            .unwrap_or(DEFAULT_HTTP_ATTEMPTS)
+    }
+
+    fn retry_base_delay() -> Duration {
+        let milliseconds = std::env::var("BUILD_HTTP_RETRY_DELAY_MS")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(DEFAULT_HTTP_RETRY_DELAY_MS);
+        Duration::from_millis(milliseconds)
+    }
+
+    /// Doubles with every attempt, saturating instead of overflowing when many
+    /// attempts are configured.
+    const fn retry_delay(base_delay: Duration, attempt: u32) -> Duration {
+        base_delay.saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+    }
+
+    fn request_timeout_secs() -> u64 {
+        std::env::var("BUILD_REQUEST_TIMEOUT_SECS")
+            .ok()
+            .and_then(|value| value.trim().parse().ok())
+            .filter(|&seconds| seconds > 0)
+            .unwrap_or(DEFAULT_HTTP_TIMEOUT_SECS)
+    }
+
+    fn is_timeout_error(error: &minreq::Error) -> bool {
+        matches!(
+            error,
+            minreq::Error::IoError(io_error)
+                if matches!(io_error.kind(), std::io::ErrorKind::TimedOut | std::io::ErrorKind::WouldBlock)
+        )
+    }
+
+    fn min_request_interval() -> Duration {
+        let milliseconds = std::env::var("BUILD_MIN_REQUEST_INTERVAL_MS")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(0);
+        Duration::from_millis(milliseconds)
+    }
+
+    fn wait_for_rate_limit() {
+        let min_interval = Self::min_request_interval();
+        if min_interval.is_zero() {
+            return;
+        }
+
+        let mut last_request_start = LAST_REQUEST_START.lock().unwrap();
+        if let Some(last_start) = *last_request_start {
+            let elapsed = last_start.elapsed();
+            if let Some(remaining) = min_interval.checked_sub(elapsed) {
+                std::thread::sleep(remaining);
+            }
+        }
+        *last_request_start = Some(Instant::now());
+    }
+
+    fn send_request(url: &str) -> Result<minreq::Response> {
+        let max_attempts = Self::max_attempts();
+        let base_delay = Self::retry_base_delay();
+        let mut attempt = 1;
+
+        loop {
+            match Self::send_single_request(url) {
+                RequestOutcome::Success(response) => return Ok(response),
+                RequestOutcome::Fatal(error) => return Err(error),
+                RequestOutcome::Retryable(error) if attempt >= max_attempts => {
+                    return Err(error.context(format!("Giving up on {url} after {attempt} attempts")));
+                }
+                RequestOutcome::Retryable(_) => {
+                    std::thread::sleep(Self::retry_delay(base_delay, attempt));
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    fn send_single_request(url: &str) -> RequestOutcome {
+        Self::wait_for_rate_limit();
+        let timeout = Self::request_timeout_secs();
+        match minreq::get(url)
+            .with_header("User-Agent", "eu-catalog-builder/1.0")
+            .with_timeout(timeout)
+            .send()
+        {
+            Err(error) if Self::is_timeout_error(&error) => RequestOutcome::Retryable(
+                anyhow::Error::new(error).context(format!("Request to {url} timed out after {timeout}s"))
+            ),
+            Err(error) => {
+                RequestOutcome::Retryable(anyhow::Error::new(error).context(format!("Failed to send request to {url}")))
+            }
+            Ok(response) if response.status_code == 200 => RequestOutcome::Success(response),
+            Ok(response) => {
+                let status = response.status_code;
+                let error = anyhow::anyhow!("HTTP error {status} from {url}");
+                if (500..600).contains(&status) {
+                    RequestOutcome::Retryable(error)
+                } else {
+                    RequestOutcome::Fatal(error)
+                }
+            }
+        }
+    }
+
+    fn fetch_text(url: &str) -> Result<String> {
+        let cache_file = ScraperCache::directory().map(|directory| ScraperCache::file_path(&directory, url));
+        if let Some(cached_text) = cache_file.as_deref().and_then(ScraperCache::read_fresh) {
+            return Ok(cached_text);
+        }
+
+        let text = Self::send_request(url)?
+            .as_str()
+            .map(std::string::ToString::to_string)?;
+
+        if let Some(cache_file) = cache_file {
+            ScraperCache::write(&cache_file, &text);
+        }
+
+        Ok(text)
+    }
+
+    fn fetch_bytes(url: &str) -> Result<Vec<u8>> {
+        if let Some(directory) = CatalogFixtures::directory() {
+            return CatalogFixtures::read_bytes(&directory, url);
+        }
+
+        Ok(Self::send_request(url)?.as_bytes().to_vec())
+    }
+
+    fn fetch_html(url: &str) -> Result<Html> {
+        if let Some(directory) = CatalogFixtures::directory() {
+            return CatalogFixtures::read_text(&directory, url).map(|text| Html::parse_document(&text));
+        }
+
+        Self::fetch_text(url).map(|text| Html::parse_document(&text))
+    }
+}
+
+// ===== CATALOG FIXTURES =====
+
+struct CatalogFixtures;
+
+/// Read from `CATALOG_FIXTURES_DIR` on first use. Tests point it at their own
+/// fixtures instead of mutating the process environment.
+static FIXTURES_DIRECTORY: LazyLock<Mutex<Option<PathBuf>>> =
+    LazyLock::new(|| Mutex::new(std::env::var_os("CATALOG_FIXTURES_DIR").map(PathBuf::from)));
+
+impl CatalogFixtures {
+    fn directory() -> Option<PathBuf> {
+        FIXTURES_DIRECTORY.lock().unwrap().clone()
+    }
+
+    // Mirrors the URL path below the fixtures directory, so `/category/browsers`
+    // is read from `category/browsers.html` and assets keep their own extension.
+    fn file_path(directory: &Path, url: &str) -> PathBuf {
+        let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+        let path = without_scheme
+            .split_once('/')
+            .map_or("", |(_, path)| path)
+            .split(['?', '#'])
+            .next()
+            .unwrap_or_default()
+            .trim_matches('/');
+
+        let relative_path = if path.is_empty() { "index" } else { path };
+        let file_path = directory.join(relative_path);
+        if file_path.extension().is_some() {
+            file_path
+        } else {
+            file_path.with_extension("html")
+        }
+    }
+
+    fn read_text(directory: &Path, url: &str) -> Result<String> {
+        let path = Self::file_path(directory, url);
+        std::fs::read_to_string(&path)
+            .with_context(|| format!("Missing fixture {path} for {url}", path = path.display()))
+    }
+
+    fn read_bytes(directory: &Path, url: &str) -> Result<Vec<u8>> {
+        let path = Self::file_path(directory, url);
+        std::fs::read(&path)
+            .with_context(|| format!("Missing fixture {path} for {url}", path = path.display()))
+    }
+}
+
+// ===== DOCUMENT SELECTORS =====
+
+struct DocumentSelectors {
+    heading: Selector,
+    first_paragraph: Selector,
+    title_tag: Selector,
+    category_link: Selector,
+    category_icon: Selector,
+    product_prose: Selector,
+    product_logo: Selector,
+    product_link: Selector,
+    product_country: Selector,
+    product_other_websites: Selector,
+    product_website: Selector,
+}
+
+static DOCUMENT_SELECTORS: LazyLock<DocumentSelectors> = LazyLock::new(|| DocumentSelectors {
+    heading: Selector::parse("h1").unwrap(),
+    first_paragraph: Selector::parse(".prose > p:first-child").unwrap(),
+    title_tag: Selector::parse("title").unwrap(),
+    category_link: Selector::parse("a[href*='/category/']").unwrap(),
+    category_icon: Selector::parse("img[src*='/categoryLogo/']").unwrap(),
+    product_prose: Selector::parse(".prose").unwrap(),
+    product_logo: Selector::parse("img[src*='/productLogo/']").unwrap(),
+    product_link: Selector::parse("div > a[href*='/product/']").unwrap(),
+    product_country: Selector::parse("img[src*='countryFlags'] + span").unwrap(),
+    product_other_websites: Selector::parse("article .items-center a").unwrap(),
+    product_website: Selector::parse(&format!(
+        r#"a[href^="http"]:not([href*="{host}"]):not(:where(
+                [href*="facebook.com"], [href*="fb.com"],
+                [href*="twitter.com"], [href*="x.com"],
+                [href*="linkedin.com"], [href*="instagram.com"],
+                [href*="youtube.com"], [href*="youtu.be"],
+                [href*="mastodon"], [href*="github.com"],
+                [href*="gitlab.com"], [href*="tiktok.com"],
+                [href*="pinterest.com"], [href*="reddit.com"],
+                [href*="snapchat.com"], [href*="discord.com"],
+                [href*="telegram.org"]
+            )) span"#,
+        host = UrlBuilder::base_host()
+    ))
+    .unwrap(),
+});
+
+// ===== SCRAPER CACHE =====
+
+struct ScraperCache;
+
+impl ScraperCache {
+    fn directory() -> Option<PathBuf> {
+        std::env::var_os("SCRAPER_CACHE_DIR").map(PathBuf::from)
+    }
+
+    fn max_age() -> Option<Duration> {
+        if std::env::var("SCRAPER_CACHE_IGNORE_AGE").is_ok_and(|value| value == "1") {
+            return None;
+        }
+
+        let seconds = std::env::var("SCRAPER_CACHE_MAX_AGE_SECS")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(DEFAULT_SCRAPER_CACHE_MAX_AGE_SECS);
+        Some(Duration::from_secs(seconds))
+    }
+
+    // FNV-1a keeps cache file names stable across toolchains, unlike `DefaultHasher`.
+    fn cache_key(url: &str) -> String {
+        let hash = url.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+        format!("{hash:016x}")
+    }
+
+    fn file_path(directory: &Path, url: &str) -> PathBuf {
+        directory.join(format!("{key}.html", key = Self::cache_key(url)))
+    }
+
+    fn is_fresh(modified: std::time::SystemTime, max_age: Option<Duration>) -> bool {
+        max_age.is_none_or(|max_age| modified.elapsed().is_ok_and(|age| age <= max_age))
+    }
+
+    fn read_fresh(path: &Path) -> Option<String> {
+        let modified = std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok()?;
+        if Self::is_fresh(modified, Self::max_age()) {
+            std::fs::read_to_string(path).ok()
+        } else {
+            None
+        }
+    }
+
+    fn write(path: &Path, text: &str) {
+        let result = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|()| std::fs::write(path, text));
+
+        if let Err(error) = result {
+            println!("cargo:warning=Failed to write scraper cache {path}: {error}", path = path.display());
+        }
+    }
+}
+
+// ===== PROGRESS REPORTER =====
+
+static SCRAPED_CATEGORIES: AtomicUsize = AtomicUsize::new(0);
+static SCRAPED_PRODUCTS: AtomicUsize = AtomicUsize::new(0);
+
+struct ProgressReporter;
+
+impl ProgressReporter {
+    fn is_enabled() -> bool {
+        std::env::var("BUILD_VERBOSE").is_ok_and(|value| value == "1")
+    }
+
+    fn reset(counter: &AtomicUsize) {
+        counter.store(0, Ordering::Relaxed);
+    }
+
+    /// Called by workers once per scraped page. Only every
+    /// `PROGRESS_REPORT_INTERVAL`th page and the last one are reported.
+    fn record(counter: &AtomicUsize, total: usize, label: &str) {
+        let completed = counter.fetch_add(1, Ordering::Relaxed) + 1;
+        if Self::is_enabled() && (completed.is_multiple_of(PROGRESS_REPORT_INTERVAL) || completed == total) {
+            println!("cargo:warning=scraped {completed}/{total} {label}");
+        }
+    }
+}
+
+// ===== CONCURRENT EXECUTOR =====
+
+struct ConcurrentExecutor;
+
+impl ConcurrentExecutor {
+    fn is_single_threaded() -> bool {
+        std::env::var("SINGLE_THREAD_BUILD").is_ok_and(|value| value.to_lowercase() == "true")
+    }
+
+    fn execute_and_collect<I, T, F, R>(items: I, worker: F) -> Result<(Vec<R>, Vec<Icon>)>
+    where
+        I: IntoIterator<Item = T>,
+        T: Send + 'static,
+        F: Fn(T) -> Result<(R, Vec<Icon>)> + Send + Sync + Copy + 'static,
+        R: Send + 'static,
+    {
+        let items: Vec<T> = items.into_iter().collect();
+        let mut results = Vec::with_capacity(items.len());
+        let mut all_icons = Vec::with_capacity(items.len());
+
+        if Self::is_single_threaded() {
+            for item in items {
+                let (result, icons) = worker(item)?;
+                results.push(result);
+                all_icons.extend(icons);
+            }
+        } else {
+            for (result, icons) in Self::execute_bounded(items, worker)? {
+                results.push(result);
+                all_icons.extend(icons);
+            }
+        }
+
+        Ok((results, all_icons))
+    }
+
+    fn execute_parallel<I, T, F>(items: I, worker: F) -> Result<()>
+    where
+        I: IntoIterator<Item = T>,
+        T: Send + 'static,
+        F: Fn(T) -> Result<()> + Send + Sync + Copy + 'static,
+    {
+        let items: Vec<T> = items.into_iter().collect();
+
+        if Self::is_single_threaded() {
+            for item in items {
+                worker(item)?;
+            }
+        } else {
+            Self::execute_bounded(items, worker)?;
+        }
+
+        Ok(())
+    }
+
+    fn thread_count() -> usize {
+        std::env::var("BUILD_THREADS")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .filter(|&threads| threads > 0)
+            .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get))
+    }
+
+    fn execute_bounded<T, F, R>(items: Vec<T>, worker: F) -> Result<Vec<R>>
+    where
+        T: Send,
+        F: Fn(T) -> Result<R> + Send + Sync + Copy,
+        R: Send,
+    {
+        let item_count = items.len();
+        let thread_count = Self::thread_count().min(item_count.max(1));
+        let queue = std::sync::Mutex::new(items.into_iter().enumerate());
+        let slots = std::sync::Mutex::new((0..item_count).map(|_| None).collect::<Vec<Option<Result<R>>>>());
+        let failed = std::sync::atomic::AtomicBool::new(false);
+
+        std::thread::scope(|scope| {
+            let handles = (0..thread_count)
+                .map(|_| {
+                    scope.spawn(|| {
+                        while !failed.load(std::sync::atomic::Ordering::Relaxed) {
+                            let Some((index, item)) = queue.lock().unwrap().next() else {
+                                break;
+                            };
+
+                            let result = worker(item);
+                            if result.is_err() {
+                                failed.store(true, std::sync::atomic::Ordering::Relaxed);
+                            }
+                            slots.lock().unwrap()[index] = Some(result);
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            for handle in handles {
+                handle
+                    .join()
+                    .map_err(|error| anyhow::anyhow!("Thread panicked: {error:?}"))?;
+            }
+
+            Ok::<_, anyhow::Error>(())
+        })?;
+
+        let mut results = Vec::with_capacity(item_count);
+        let mut first_error = None;
+        for slot in slots.into_inner().unwrap() {
+            match slot {
+                Some(Ok(result)) => results.push(result),
+                Some(Err(error)) => {
+                    first_error.get_or_insert(error);
+                }
+                None => {}
+            }
+        }
+
+        first_error.map_or(Ok(results), Err)
+    }
+}
+
+// ===== URL BUILDERS =====
+
+struct UrlBuilder;
+
+impl UrlBuilder {
+    /// Base URL of the scraped site. Defaults to european-alternatives.eu and can
+    /// be pointed at a mirror or local snapshot with `CATALOG_BASE_URL`.
+    fn base_url() -> String {
+        Self::resolve_base_url(std::env::var("CATALOG_BASE_URL").ok().as_deref())
+    }
+
+    fn resolve_base_url(configured: Option<&str>) -> String {
+        configured
+            .map(|url| url.trim().trim_end_matches('/'))
+            .filter(|url| !url.is_empty())
+            .unwrap_or(DEFAULT_BASE_URL)
+            .to_string()
+    }
+
+    fn is_base_url_overridden() -> bool {
+        Self::base_url() != DEFAULT_BASE_URL
+    }
+
+    /// Host of `base_url`, used to exclude links back to the scraped site when
+    /// looking for a product's own website.
+    fn base_host() -> String {
+        let base_url = Self::base_url();
+        let without_scheme = base_url.split_once("://").map_or(base_url.as_str(), |(_, rest)| rest);
+        without_scheme.split(['/', ':']).next().unwrap_or_default().to_string()
+    }
+
+    /// Category page under `CATALOG_BASE_URL`, or the public site when unset.
+    fn build_category_url(slug: &str) -> String {
+        format!("{base_url}/category/{slug}", base_url = Self::base_url())
+    }
+
+    /// Category index under `CATALOG_BASE_URL`, or the public site when unset.
+    fn build_categories_index_url() -> String {
+        format!("{base_url}/categories", base_url = Self::base_url())
+    }
+
+    /// Flags come from the public CDN by default. When `CATALOG_BASE_URL` is
+    /// overridden they are expected under the same base, so a local snapshot
+    /// needs no second host.
+    fn build_country_flag_url(code: &str) -> String {
+        let flags_base_url = if Self::is_base_url_overridden() {
+            Self::base_url()
+        } else {
+            DEFAULT_FLAGS_BASE_URL.to_string()
+        };
+        format!("{flags_base_url}/countryFlags/4x3/{code}.svg")
+    }
+
+    /// Drops the query string, fragment and trailing slashes so the same page
+    /// reached through different links is only scraped once.
+    fn normalize_url(url: &str) -> String {
+        let end = url.find(['?', '#']).unwrap_or(url.len());
+        url[..end].trim_end_matches('/').to_string()
+    }
+
+    fn extract_slug_from_href(href: &str) -> Option<String> {
+        href.split('/')
+            .next_back()
+            .map(std::string::ToString::to_string)
+    }
+}
+
+// ===== DOCUMENT EXTRACTOR =====
+
+struct DocumentExtractor;
+
+impl DocumentExtractor {
+    fn extract_text(document: &Html, selector: &Selector, context: &str) -> Result<String> {
+        document
+            .select(selector)
+            .next()
+            .map(|element| element.text().collect::<String>().trim().to_string())
+            .context(format!("{context} not found"))
+    }
+
+    fn extract_attribute(
+        document: &Html,
+        selector: &Selector,
+        attribute: &str,
+        context: &str,
+    ) -> Result<String> {
+        document
+            .select(selector)
+            .next()
+            .and_then(|element| element.value().attr(attribute))
+            .map(std::string::ToString::to_string)
+            .context(format!("{context} not found"))
+    }
+
+    fn extract_optional_attribute(
+        document: &Html,
+        selector: &Selector,
+        attribute: &str,
+    ) -> Option<String> {
+        document
+            .select(selector)
+            .next()
+            .and_then(|element| element.value().attr(attribute))
+            .map(std::string::ToString::to_string)
+    }
+
+    fn collect_unique_href_values(document: &Html, selector: &Selector) -> Vec<String> {
+        let mut seen = HashSet::new();
+        let mut results = Vec::new();
+
+        for anchor in document.select(selector) {
+            if
+                let Some(href) = anchor.value().attr("href")
+                && seen.insert(href)
+            {
+                results.push(href.to_string());
+            }
+        }
+
+        results
+    }
+}
+
+// ===== CATEGORY EXTRACTOR =====
+
+struct CategoryExtractor;
+
+impl CategoryExtractor {
+    fn extract_all_categories() -> Result<(Vec<Category>, Vec<Icon>)> {
+        let category_urls = Self::discover_category_urls()?;
+        let total = category_urls.len();
+        ProgressReporter::reset(&SCRAPED_CATEGORIES);
+
+        ConcurrentExecutor::execute_and_collect(category_urls, move |(url, slug)| {
+            let result = Self::extract_single_category(&url, slug).map(|(cat, icon)| (cat, vec![icon]));
+            ProgressReporter::record(&SCRAPED_CATEGORIES, total, "categories");
+            result
+        })
+    }
+
+    fn discover_category_urls() -> Result<HashMap<String, String>> {
+        let document = HttpClient::fetch_html(&UrlBuilder::build_categories_index_url())?;
+        let hrefs = DocumentExtractor::collect_unique_href_values(
+            &document,
+            &DOCUMENT_SELECTORS.category_link,
+        );
+        let results = hrefs
+            .into_iter()
+            .filter_map(|href| UrlBuilder::extract_slug_from_href(&href).map(|slug| (href, slug)))
+            .collect();
+
+        Ok(results)
+    }
+
+    fn remove_european_prefix(name: &str) -> Option<String> {
+        if name.len() >= 10
+            && name.chars().take(8).collect::<String>().to_lowercase() == "european"
+            && name.chars().nth(8) == Some(' ')
+        {
+            let remaining_chars = name.chars().skip(9).collect::<Vec<char>>();
+            if let Some(first_remaining_char) = remaining_chars.first() {
+                return Some(format!(
+                    "{}{}",
+                    first_remaining_char.to_uppercase(),
+                    remaining_chars.iter().skip(1).collect::<String>()
+                ));
+            }
+        }
+        None
+    }
+
+    fn extract_single_category(url: &str, slug: String) -> Result<(Category, Icon)> {
+        let document = HttpClient::fetch_html(url)?;
+        let name = DocumentExtractor::extract_text(
+            &document,
+            &DOCUMENT_SELECTORS.heading,
+            "Category name",
+        )?;
+        let name = Self::remove_european_prefix(&name).unwrap_or(name);
+        let description = DocumentExtractor::extract_text(
+            &document,
+            &DOCUMENT_SELECTORS.first_paragraph,
+            "Category description",
+        )?;
+        let summary = description
+            .split('.')
+            .next()
+            .map_or_else(|| description.clone(), |s| format!("{s}."));
+        let icon = Self::extract_category_icon(&document, &name)?;
+        let category = Category {
+            slug,
+            name,
+            description,
+            summary,
+            icon: icon.name.clone(),
+        };
+
+        Ok((category, icon))
+    }
+
+    fn extract_category_icon(document: &Html, name: &str) -> Result<Icon> {
+        let icon_url = DocumentExtractor::extract_attribute(
+            document,
+            &DOCUMENT_SELECTORS.category_icon,
+            "src",
+            "Category icon",
+        )?;
+
+        Icon::from_url(icon_url, name)
+    }
+}
+
+// ===== PRODUCT EXTRACTOR =====
+
+struct ProductExtractor;
+
+impl ProductExtractor {
+    fn extract_all_products(categories: &[Category]) -> Result<(Vec<Product>, Vec<Icon>)> {
+        let product_urls = Self::discover_product_urls(categories)?;
+        let total = product_urls.len();
+        ProgressReporter::reset(&SCRAPED_PRODUCTS);
+
+        let (products, icons) = ConcurrentExecutor::execute_and_collect(product_urls, move |(url, categories)| {
+            let result = Self::extract_single_product_with_icons(&url, categories);
+            ProgressReporter::record(&SCRAPED_PRODUCTS, total, "products");
+            result
+        })?;
+
+        Ok((Self::merge_duplicate_products(products), icons))
+    }
+
+    fn merge_duplicate_products(products: Vec<Product>) -> Vec<Product> {
+        let mut merged: Vec<Product> = Vec::with_capacity(products.len());
+        let mut index_by_name = HashMap::new();
+
+        for product in products {
+            if let Some(&index) = index_by_name.get(&product.name) {
+                let existing: &mut Product = &mut merged[index];
+                println!("cargo:warning=Merging duplicate product '{name}'", name = product.name);
+                for slug in product.categories {
+                    if !existing.categories.contains(&slug) {
+                        existing.categories.push(slug);
+                    }
+                }
+            } else {
+                index_by_name.insert(product.name.clone(), merged.len());
+                merged.push(product);
+            }
+        }
+
+        merged
+    }
+
+    fn discover_product_urls(categories: &[Category]) -> Result<HashMap<String, HashSet<String>>> {
+        let mut product_urls = HashMap::new();
+
+        for category in categories {
+            Self::collect_product_urls_for_category(&mut product_urls, category)?;
+        }
+
+        Ok(product_urls)
+    }
+
+    fn collect_product_urls_for_category(
+        product_urls: &mut HashMap<String, HashSet<String>>,
+        category: &Category,
+    ) -> Result<()> {
+        let category_url = UrlBuilder::build_category_url(&category.slug);
+        let document = HttpClient::fetch_html(&category_url)?;
+
+        for element in document.select(&DOCUMENT_SELECTORS.product_link) {
+            if let Some(url) = element.value().attr("href") {
+                let category_list = product_urls.entry(UrlBuilder::normalize_url(url)).or_default();
+
+                category_list.insert(category.slug.clone());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn extract_single_product_with_icons(
+        url: &str,
+        categories: HashSet<String>,
+    ) -> Result<(Product, Vec<Icon>)> {
+        let document = HttpClient::fetch_html(url)?;
+        let product = Self::extract_product_data(&document, categories, url)?;
+        let icons = Self::extract_product_icons(&document, &product)?;
+
+        Ok((product, icons))
+    }
+
+    fn extract_product_data(
+        document: &Html,
+        categories: HashSet<String>,
+        url: &str,
+    ) -> Result<Product> {
+        let name =
+            DocumentExtractor::extract_text(document, &DOCUMENT_SELECTORS.heading, "Product name")?;
+        let name = heck::AsTitleCase(name).to_string();
+        let slug = UrlBuilder::extract_slug_from_href(url.trim_end_matches('/'))
+            .with_context(|| format!("Missing product slug in {url}"))?;
+        let source_website = url.to_string();
+        let (description, summary) = Self::extract_description_and_summary(document)?;
+        let country = Self::extract_product_country(document);
+        let logo = Self::extract_product_logo_name(document, &name)?;
+        let categories = categories.into_iter().collect();
+        let websites = Self::extract_websites(document, &source_website);
+
+        Ok(Product {
+            categories,
+            logo,
+            name,
+            slug,
+            description,
+            summary,
+            country,
+            websites,
+        })
+    }
+
+    fn extract_description_and_summary(document: &Html) -> Result<(String, String)> {
+        let description_element = document
+            .select(&DOCUMENT_SELECTORS.product_prose)
+            .next()
+            .context("Product description not found")?;
+
+        let mut description = String::new();
+        for child in description_element.children() {
+            let Some(child_element) = child.value().as_element() else {
+                continue;
+            };
+            let element_ref = ElementRef::wrap(child).expect("Child is an element");
+            let text = element_ref.text().collect::<String>();
+            let trimmed_text = text.trim();
+
+            match child_element.name() {
+                "p" if description.is_empty() => description.push_str(trimmed_text),
+                "p" => write!(description, "\n\n{trimmed_text}").unwrap(),
+                _ => break,
+            }
+        }
+
+        let summary = Self::generate_summary(&description);
+
+        Ok((description, summary))
+    }
+
+    fn generate_summary(description: &str) -> String {
+        let mut summary = String::new();
+        let mut sentence_count = 0;
+
+        for sentence in description.replace("\n\n", "\n").split('.') {
+            let trimmed_sentence = sentence.trim();
+            if !trimmed_sentence.is_empty() && sentence_count < 2 {
+                write!(summary, "{trimmed_sentence}.").unwrap();
+                sentence_count += 1;
+
+                if sentence_count == 2 {
+                    break;
+                }
+            }
+        }
+
+        summary
+    }
+
+    fn extract_websites(document: &Html, source: &str) -> Vec<(String, String)> {
+        let company_website_option = Self::extract_product_website(document);
+        let mut websites = company_website_option.map_or_else(
+            || vec![(String::from("European Alternatives"), source.to_string())],
+            |oficial_website| {
+                vec![
+                    (String::from("Company"), oficial_website),
+                    (String::from("European Alternatives"), source.to_string()),
+                ]
+            },
+        );
+
+        for element in document.select(&DOCUMENT_SELECTORS.product_other_websites) {
+            if let Some(href) = element.value().attr("href")
+                && let Some(title) = element.select(&DOCUMENT_SELECTORS.title_tag).next()
+            {
+                let title = title.text().collect::<String>().trim().to_string();
+                websites.push((title, href.to_string().trim().to_string()));
+            }
+        }
+
+        websites
+    }
+
+    fn extract_product_website(document: &Html) -> Option<String> {
+        document
+            .select(&DOCUMENT_SELECTORS.product_website)
+            .next()
+            .and_then(|span| span.parent())
+            .and_then(|anchor| anchor.value().as_element())
+            .and_then(|anchor| anchor.attr("href"))
+            .map(std::string::ToString::to_string)
+    }
+
+    fn extract_product_country(document: &Html) -> Option<Country> {
+        document
+            .select(&DOCUMENT_SELECTORS.product_country)
+            .next()
+            .and_then(|span| Country::parse(span.text().collect::<String>().trim()))
+    }
+
+    fn extract_product_logo_name(document: &Html, product_name: &str) -> Result<Option<String>> {
+        let icon = Self::extract_product_logo_icon(document, product_name)?;
+        Ok(icon.map(|icon| icon.name))
+    }
+
+    fn extract_product_icons(document: &Html, product: &Product) -> Result<Vec<Icon>> {
+        let icon = Self::extract_product_logo_icon(document, &product.name)?;
+        Ok(icon.into_iter().collect())
+    }
+
+    fn extract_product_logo_icon(document: &Html, name: &str) -> Result<Option<Icon>> {
+        DocumentExtractor::extract_optional_attribute(
+            document,
+            &DOCUMENT_SELECTORS.product_logo,
+            "src",
+        )
+        .map(|url| Icon::from_url(url, name))
+        .transpose()
+    }
+}
+
+// ===== CATALOG EXTRACTOR =====
+
+pub struct CatalogExtractor;
+
+impl CatalogExtractor {
+    /// Base URL the catalog is scraped from, honouring `CATALOG_BASE_URL`.
+    #[must_use]
+    pub fn source_url() -> String {
+        UrlBuilder::base_url()
+    }
+
+    pub fn extract_complete_catalog() -> Result<(Vec<Category>, Vec<Product>, Vec<Icon>)> {
+        let (categories, category_icons) = CategoryExtractor::extract_all_categories()?;
+        let (products, product_icons) = ProductExtractor::extract_all_products(&categories)?;
+        let country_icons = Self::extract_country_flags_icons()?;
+
+        let icons = category_icons
+            .into_iter()
+            .chain(product_icons)
+            .chain(country_icons)
+            .collect::<Vec<_>>();
+
+        Ok((categories, products, icons))
+    }
+
+    fn extract_country_flags_icons() -> Result<Vec<Icon>> {
+        let mut icons = Vec::with_capacity(Country::COUNT);
+        for country in Country::all() {
+            let flag_url = UrlBuilder::build_country_flag_url(country.code());
+            let icon = Icon::from_url(flag_url, country.slug())?;
+            icons.push(icon);
+        }
+
+        Ok(icons)
+    }
+}
+
+// ===== CATALOG CODE GENERATION =====
+
+#[allow(clippy::struct_field_names)]
+pub struct CatalogIndexMaps {
+    category_slug_to_index: HashMap<String, usize>,
+    product_name_to_index: HashMap<String, usize>,
+    product_slug_to_index: HashMap<String, usize>,
+    products_by_category_index: Vec<Vec<usize>>,
+    products_by_country_index: Vec<Vec<usize>>,
+}
+
+impl CatalogIndexMaps {
+    #[must_use]
+    pub fn build_from_catalog(categories: &[Category], products: &[Product]) -> Self {
+        let category_slug_to_index = Self::build_category_slug_index(categories);
+        let product_name_to_index = Self::build_product_name_index(products);
+        let product_slug_to_index = Self::build_product_slug_index(products);
+        let products_by_category_index = Self::build_products_by_category_index(
+            products,
+            &category_slug_to_index,
+            categories.len(),
+        );
+        let products_by_country_index = Self::build_products_by_country_index(products);
+
+        Self::warn_about_unmapped_categories(products, &category_slug_to_index);
+
+        Self {
+            category_slug_to_index,
+            product_name_to_index,
+            product_slug_to_index,
+            products_by_category_index,
+            products_by_country_index,
+        }
+    }
+
+    fn find_unmapped_categories<'a>(
+        products: &'a [Product],
+        category_slug_to_index: &HashMap<String, usize>,
+    ) -> Vec<(&'a str, Vec<&'a str>)> {
+        products
+            .iter()
+            .filter_map(|product| {
+                let unknown_slugs = product
+                    .categories
+                    .iter()
+                    .filter(|slug| !category_slug_to_index.contains_key(*slug))
+                    .map(String::as_str)
+                    .collect::<Vec<_>>();
+
+                (!unknown_slugs.is_empty()).then_some((product.name.as_str(), unknown_slugs))
+            })
+            .collect()
+    }
+
+    fn warn_about_unmapped_categories(
+        products: &[Product],
+        category_slug_to_index: &HashMap<String, usize>,
+    ) {
+        for (product_name, unknown_slugs) in Self::find_unmapped_categories(products, category_slug_to_index) {
+            println!(
+                "cargo:warning=Product '{product_name}' references unknown categories, ignoring: {slugs}",
+                slugs = unknown_slugs.join(", ")
+            );
+        }
+    }
+
+    fn build_category_slug_index(categories: &[Category]) -> HashMap<String, usize> {
+        categories
+            .iter()
+            .enumerate()
+            .map(|(index, category)| (category.slug.clone(), index))
+            .collect()
+    }
+
+    fn build_product_name_index(products: &[Product]) -> HashMap<String, usize> {
+        products
+            .iter()
+            .enumerate()
+            .map(|(index, product)| (product.name.clone(), index))
+            .collect()
+    }
+
+    fn build_product_slug_index(products: &[Product]) -> HashMap<String, usize> {
+        products
+            .iter()
+            .enumerate()
+            .map(|(index, product)| (product.slug.clone(), index))
+            .collect()
+    }
+
+    fn build_products_by_category_index(
+        products: &[Product],
+        category_slug_to_index: &HashMap<String, usize>,
+        categories_count: usize,
+    ) -> Vec<Vec<usize>> {
+        let mut products_by_category = vec![Vec::new(); categories_count];
+
+        for (product_index, product) in products.iter().enumerate() {
+            Self::assign_product_to_categories(
+                product,
+                product_index,
+                category_slug_to_index,
+                &mut products_by_category,
+            );
+        }
+
+        products_by_category
+    }
+
+    fn assign_product_to_categories(
+        product: &Product,
+        product_index: usize,
+        category_slug_to_index: &HashMap<String, usize>,
+        products_by_category: &mut [Vec<usize>],
+    ) {
+        for slug in &product.categories {
+            if let Some(&category_index) = category_slug_to_index.get(slug) {
+                products_by_category[category_index].push(product_index);
+            }
+        }
+    }
+
+    fn build_products_by_country_index(products: &[Product]) -> Vec<Vec<usize>> {
+        let mut products_by_country = vec![Vec::new(); Country::COUNT];
+
+        for (product_index, product) in products.iter().enumerate() {
+            Self::assign_product_to_country(product, product_index, &mut products_by_country);
+        }
+
+        products_by_country
+    }
+
+    fn assign_product_to_country(
+        product: &Product,
+        product_index: usize,
+        products_by_country: &mut [Vec<usize>],
+    ) {
+        if let Some(country) = product.country {
+            products_by_country[country as usize].push(product_index);
+        }
+    }
+}
+
+// ===== CATALOG CODE BUILDER =====
+
+pub struct CatalogCodeBuilder;
+
+impl CatalogCodeBuilder {
+    fn format_indexed_vector_collection<T: Debug>(vectors: &[Vec<T>]) -> String {
+        let formatted_vectors = vectors
+            .iter()
+            .map(|vector| format!("&{vector:?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!("&[{formatted_vectors}]")
+    }
+
+    fn format_phf_hash_map<K: AsRef<str>>(map: &HashMap<K, usize>) -> String {
+        let mut phf_builder = Map::new();
+        for (key, value) in map {
+            phf_builder.entry(key.as_ref(), value.to_string());
+        }
+
+        phf_builder.build().to_string()
+    }
+
+    fn format_optional_country_field(country: Option<Country>) -> String {
+        country.map_or_else(
+            || "None".to_string(),
+            |country| format!("Some(crate::models::Country::{country:?})"),
+        )
+    }
+
+    // Unknown slugs were already warned about while building the index maps.
+    fn format_category_indices_list(
+        product_categories: &[String],
+        category_slug_to_index: &HashMap<String, usize>,
+    ) -> String {
+        product_categories
+            .iter()
+            .filter_map(|slug| category_slug_to_index.get(slug))
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn format_category_struct(category: &Category) -> String {
+        format!(
+            "crate::models::Category {{
+                slug: {slug:?},
+                name: {name:?},
+                summary: {summary:?},
+                description: {description:?},
+                icon: {icon:?}
+            }}",
+            slug = category.slug,
+            name = category.name,
+            summary = category.summary,
+            description = category.description,
+            icon = category.icon
+        )
+    }
+
+    fn format_product_struct(index_maps: &CatalogIndexMaps, product: &Product) -> String {
+        let country = Self::format_optional_country_field(product.country);
+        let categories = Self::format_category_indices_list(
+            &product.categories,
+            &index_maps.category_slug_to_index,
+        );
+
+        format!(
+            "crate::models::Product {{
+                categories: &[{categories}],
+                name: {name:?},
+                slug: {slug:?},
+                country: {country},
+                description: {description:?},
+                summary: {summary:?},
+                logo: {logo:?},
+                websites: &{websites:?}
+            }}",
+            name = product.name,
+            slug = product.slug,
+            description = product.description,
+            summary = product.summary,
+            logo = product.logo,
+            websites = product.websites,
+        )
+    }
+
+    fn format_categories_array(categories: &[Category]) -> String {
+        categories
+            .iter()
+            .map(Self::format_category_struct)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn format_products_array(products: &[Product], index_maps: &CatalogIndexMaps) -> String {
+        products
+            .iter()
+            .map(|product| Self::format_product_struct(index_maps, product))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    #[must_use]
+    pub fn build_catalog_struct_code(
+        categories: &[Category],
+        products: &[Product],
+        index_maps: &CatalogIndexMaps,
+    ) -> String {
+        let categories_map = Self::format_phf_hash_map(&index_maps.category_slug_to_index);
+        let products_map = Self::format_phf_hash_map(&index_maps.product_name_to_index);
+        let product_slugs_map = Self::format_phf_hash_map(&index_maps.product_slug_to_index);
+        let category_products =
+            Self::format_indexed_vector_collection(&index_maps.products_by_category_index);
+        let country_products =
+            Self::format_indexed_vector_collection(&index_maps.products_by_country_index);
+        let categories_array = Self::format_categories_array(categories);
+        let products_array = Self::format_products_array(products, index_maps);
+        let marker = CatalogVersion::code_marker();
+        let version = Catalog::VERSION;
+
+        format!(
+            "{marker}
+            crate::models::Catalog {{
+                version: {version},
+                categories: &[{categories_array}],
+                products: &[{products_array}],
+                categories_map: {categories_map},
+                products_map: {products_map},
+                product_slugs_map: {product_slugs_map},
+                category_products: {category_products},
+                country_products: {country_products},
+                category_member_sets: std::sync::OnceLock::new()
+            }}"
+        )
+    }
+}
+
+// ===== CATALOG VALIDATOR =====
+
+#[derive(Default)]
+pub struct CatalogValidationReport {
+    errors: Vec<String>,
+    warnings: Vec<String>,
+}
+
+pub struct CatalogValidator;
+
+impl CatalogValidator {
+    #[must_use]
+    pub fn validate(categories: &[Category], products: &[Product]) -> CatalogValidationReport {
+        let mut report = CatalogValidationReport::default();
+        let category_slugs = categories
+            .iter()
+            .map(|category| category.slug.as_str())
+            .collect::<HashSet<_>>();
+
+        for (index, category) in categories.iter().enumerate() {
+            Self::validate_category(index, category, &mut report);
+        }
+
+        for (index, product) in products.iter().enumerate() {
+            Self::validate_product(index, product, &category_slugs, &mut report);
+        }
+
+        report
+    }
+
+    fn validate_category(index: usize, category: &Category, report: &mut CatalogValidationReport) {
+        if category.name.trim().is_empty() {
+            report.errors.push(format!("Category #{index} ({slug}) has an empty name", slug = category.slug));
+        }
+        if category.description.trim().is_empty() {
+            report.errors.push(format!("Category '{name}' has an empty description", name = category.name));
+        }
+    }
+
+    fn validate_product(
+        index: usize,
+        product: &Product,
+        category_slugs: &HashSet<&str>,
+        report: &mut CatalogValidationReport,
+    ) {
+        let name = &product.name;
+        if name.trim().is_empty() {
+            report.errors.push(format!("Product #{index} has an empty name"));
+        }
+        if product.description.trim().is_empty() {
+            report.errors.push(format!("Product '{name}' has an empty description"));
+        }
+        if product.logo.is_none() {
+            report.warnings.push(format!("Product '{name}' has no logo"));
+        }
+        if product.categories.is_empty() {
+            report.errors.push(format!("Product '{name}' does not belong to any category"));
+        }
+        for slug in &product.categories {
+            if !category_slugs.contains(slug.as_str()) {
+                report.errors.push(format!("Product '{name}' references unknown category '{slug}'"));
+            }
+        }
+    }
+}
+
+impl CatalogValidationReport {
+    pub fn into_result(self) -> Result<()> {
+        for warning in &self.warnings {
+            println!("cargo:warning={warning}");
+        }
+
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            bail!(
+                "Scraped catalog failed validation with {count} problems:\n{errors}",
+                count = self.errors.len(),
+                errors = self.errors.join("\n")
+            )
+        }
+    }
+}
+
+// ===== CATALOG JSON BUILDER =====
+
+pub struct CatalogJsonBuilder;
+
+impl CatalogJsonBuilder {
+    fn format_string_array(values: &[String]) -> String {
+        let values = values.iter().map(|value| escape_json(value)).collect::<Vec<_>>();
+        format!("[{values}]", values = values.join(", "))
+    }
+
+    fn format_category(category: &Category) -> String {
+        format!(
+            "{{\"slug\": {slug}, \"name\": {name}, \"description\": {description}, \"summary\": {summary}, \"icon\": {icon}}}",
+            slug = escape_json(&category.slug),
+            name = escape_json(&category.name),
+            description = escape_json(&category.description),
+            summary = escape_json(&category.summary),
+            icon = escape_json(&category.icon),
+        )
+    }
+
+    fn format_product(product: &Product) -> String {
+        let mut categories = product.categories.clone();
+        categories.sort();
+        let country = product
+            .country
+            .map_or_else(|| String::from("null"), |country| escape_json(country.slug()));
+        let websites = product
+            .websites
+            .iter()
+            .map(|(caption, url)| {
+                format!(
+                    "{{\"caption\": {caption}, \"url\": {url}}}",
+                    caption = escape_json(caption),
+                    url = escape_json(url)
+                )
+            })
+            .collect::<Vec<_>>();
+
+        format!(
+            "{{\"name\": {name}, \"slug\": {slug}, \"country\": {country}, \"categories\": {categories}, \"logo\": {logo}, \"summary\": {summary}, \"description\": {description}, \"websites\": [{websites}]}}",
+            name = escape_json(&product.name),
+            slug = escape_json(&product.slug),
+            categories = Self::format_string_array(&categories),
+            logo = product.logo.as_deref().map_or_else(|| String::from("null"), escape_json),
+            summary = escape_json(&product.summary),
+            description = escape_json(&product.description),
+            websites = websites.join(", "),
+        )
+    }
+
+    #[must_use]
+    pub fn build_catalog_json(categories: &[Category], products: &[Product]) -> String {
+        let categories = categories.iter().map(Self::format_category).collect::<Vec<_>>();
+        let products = products.iter().map(Self::format_product).collect::<Vec<_>>();
+
+        format!(
+            "{{\n  \"version\": {version},\n  \"categories\": [\n    {categories}\n  ],\n  \"products\": [\n    {products}\n  ]\n}}\n",
+            version = Catalog::VERSION,
+            categories = categories.join(",\n    "),
+            products = products.join(",\n    "),
+        )
+    }
+}
+
+// ===== CATALOG VERSION =====
+
+/// Reads back the catalog version stamped into previously generated files, so
+/// a cached catalog is only reused when it matches `Catalog::VERSION`.
+pub struct CatalogVersion;
+
+impl CatalogVersion {
+    const CODE_MARKER: &'static str = "// catalog-version:";
+
+    fn code_marker() -> String {
+        format!("{marker} {version}", marker = Self::CODE_MARKER, version = Catalog::VERSION)
+    }
+
+    /// The version from the marker on the first line of the generated code.
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<u32> {
+        code.lines()
+            .next()?
+            .strip_prefix(Self::CODE_MARKER)?
+            .trim()
+            .parse()
+            .ok()
+    }
+
+    /// The version from the `"version"` member that opens the catalog object.
+    #[must_use]
+    pub fn from_json(json: &str) -> Option<u32> {
+        let member = json.trim_start().strip_prefix('{')?.trim_start();
+        let value = member
+            .strip_prefix("\"version\"")?
+            .trim_start()
+            .strip_prefix(':')?
+            .trim_start();
+        let end = value
+            .find(|character: char| !character.is_ascii_digit())
+            .unwrap_or(value.len());
+        value[..end].parse().ok()
+    }
+
+    /// Errors with the expected and found versions unless `version` is
+    /// `Catalog::VERSION`.
+    pub fn check(version: Option<u32>) -> Result<()> {
+        let expected = Catalog::VERSION;
+        match version {
+            Some(found) if found == expected => Ok(()),
+            Some(found) => bail!("Catalog version mismatch: expected {expected}, found {found}"),
+            None => bail!("Catalog version mismatch: expected {expected}, found no version"),
+        }
+    }
+
+    /// Checks the version stamped into a previously generated file. `Ok(false)`
+    /// means the file does not exist yet.
+    pub fn check_file(path: &Path, read: fn(&str) -> Option<u32>) -> Result<bool> {
+        let Ok(content) = std::fs::read_to_string(path) else {
+            return Ok(false);
+        };
+        Self::check(read(&content)).with_context(|| format!("Cached catalog {path} is stale", path = path.display()))?;
+        Ok(true)
+    }
+}
+
+// ===== ICON =====
+
+#[derive(Debug, Clone)]
+pub struct Icon {
+    url: String,
+    filename: String,
+    name: String,
+    extension: String,
+}
+
+impl Icon {
+    fn from_url(url: String, name: &str) -> Result<Self> {
+        let name = heck::AsSnakeCase(name).to_string();
+        let extension = url
+            .rsplit('.')
+            .next()
+            .context("Invalid icon URL")?
+            .to_lowercase();
+
+        let final_extension = if extension == "svg" { "svg" } else { "png" };
+
+        let filename = format!("{name}.{final_extension}");
+
+        Ok(Self {
+            url,
+            filename,
+            name,
+            extension,
+        })
+    }
+
+    fn is_svg(&self) -> bool {
+        self.extension == "svg"
+    }
+
+    fn is_png(&self) -> bool {
+        self.extension == "png"
+    }
+}
+
+// ===== ICON HARVESTER =====
+
+#[derive(Default)]
+pub struct IconHarvester<'a> {
+    icon_registry: HashMap<&'a str, &'a Icon>,
+}
+
+impl<'a> IconHarvester<'a> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            icon_registry: HashMap::new(),
+        }
+    }
+
+    /// Fixture builds register the same icons, which are then read from the
+    /// logos and flags shipped with the fixtures.
+    pub fn register_icons(&mut self, icons: &'a [Icon]) -> &mut Self {
+        for icon in icons {
+            self.icon_registry.insert(&icon.url, icon);
+        }
+        self
+    }
+
+    pub fn download_all_to_directory(&self, output_directory: &Path) -> Result<&Self> {
+        let icons_to_download = self
+            .icon_registry
+            .values()
+            .map(|&icon| (icon.clone(), output_directory.to_owned()))
+            .collect::<Vec<_>>();
+
+        ConcurrentExecutor::execute_parallel(icons_to_download, |(icon, directory)| {
+            Self::download_icon_as_svg(&icon, &directory)
+        })?;
+
+        Ok(self)
+    }
+
+    fn download_icon_as_svg(icon: &Icon, directory: &Path) -> Result<()> {
+        let bytes = HttpClient::fetch_bytes(&icon.url)?;
+        let path = directory.join(&icon.filename);
+        if icon.is_svg() {
+            Self::normalize_svg_bytes(&path, &bytes)
+        } else {
+            Self::convert_image_to_png(icon, &path, &bytes)
+        }?;
+
+        Ok(())
+    }
+
+    fn normalize_svg_bytes(path: &Path, bytes: &[u8]) -> Result<()> {
+        let options = resvg::usvg::Options::default();
+        let write_options = resvg::usvg::WriteOptions::default();
+        let tree = resvg::usvg::Tree::from_data(bytes, &options)?;
+
+        let xml = tree.to_string(&write_options);
+        Ok(std::fs::write(path, xml)?)
+    }
+
+    fn convert_image_to_png(icon: &Icon, path: &Path, bytes: &[u8]) -> Result<()> {
+        if icon.is_png() {
+            std::fs::write(path, bytes)?;
+        } else {
+            let image = image::load_from_memory(bytes)?;
+            image.save_with_format(path, image::ImageFormat::Png)?;
+        }
+
+        Ok(())
+    }
+
+    fn build_resources_xml(&self) -> String {
+        self.icon_registry
+            .values()
+            .map(|icon| {
+                if icon.is_svg() {
+                    format!(
+                        "<file compressed=\"true\" preprocess=\"xml-stripblanks\" alias=\"{filename}\">{filename}</file>",
+                        filename = icon.filename
+                    )
+                } else {
+                    format!(
+                        "<file compressed=\"true\" alias=\"{filename}\">{filename}</file>",
+                        filename = icon.filename
+                    )
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    pub fn save_resources_xml_to_file(&self, path: &Path) -> Result<String> {
+        let xml_content = self.build_resources_xml();
+        std::fs::write(path, &xml_content)?;
+        Ok(xml_content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn category(slug: &str, name: &str, description: &str) -> Category {
+        Category {
+            slug: slug.to_string(),
+            name: name.to_string(),
+            description: description.to_string(),
+            summary: description.to_string(),
+            icon: slug.to_string(),
+        }
+    }
+
+    fn product(name: &str, description: &str, categories: &[&str], logo: Option<&str>) -> Product {
+        Product {
+            categories: categories.iter().map(ToString::to_string).collect(),
+            logo: logo.map(ToString::to_string),
+            name: name.to_string(),
+            slug: name.to_lowercase(),
+            description: description.to_string(),
+            summary: description.to_string(),
+            country: None,
+            websites: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn valid_catalog_has_no_problems() {
+        let categories = [category("browsers", "Browsers", "Browse the web.")];
+        let products = [product("Vivaldi", "Web browser.", &["browsers"], Some("vivaldi"))];
+
+        let report = CatalogValidator::validate(&categories, &products);
+        assert!(report.errors.is_empty());
+        assert!(report.warnings.is_empty());
+        assert!(report.into_result().is_ok());
+    }
+
+    #[test]
+    fn empty_fields_and_dangling_categories_are_errors() {
+        let categories = [category("browsers", " ", "")];
+        let products = [
+            product("", "Web browser.", &["browsers"], Some("logo")),
+            product("Vivaldi", "", &["browsers"], Some("logo")),
+            product("Orphan", "No category.", &["removed-category"], Some("logo")),
+        ];
+
+        let report = CatalogValidator::validate(&categories, &products);
+        assert_eq!(report.errors, [
+            "Category #0 (browsers) has an empty name",
+            "Category ' ' has an empty description",
+            "Product #0 has an empty name",
+            "Product 'Vivaldi' has an empty description",
+            "Product 'Orphan' references unknown category 'removed-category'",
+        ]);
+
+        let error = report.into_result().unwrap_err().to_string();
+        assert!(error.starts_with("Scraped catalog failed validation with 5 problems"));
+    }
+
+    #[test]
+    fn every_unknown_category_of_a_product_is_an_error() {
+        let categories = [category("browsers", "Browsers", "Browse the web.")];
+        let products = [
+            product("Vivaldi", "Web browser.", &["browsers", "removed-category", "old-category"], Some("logo")),
+            product("Homeless", "No categories at all.", &[], Some("logo")),
+        ];
+
+        let report = CatalogValidator::validate(&categories, &products);
+        assert_eq!(report.errors, [
+            "Product 'Vivaldi' references unknown category 'removed-category'",
+            "Product 'Vivaldi' references unknown category 'old-category'",
+            "Product 'Homeless' does not belong to any category",
+        ]);
+    }
+
+    #[test]
+    fn missing_logo_is_only_a_warning() {
+        let categories = [category("browsers", "Browsers", "Browse the web.")];
+        let products = [product("Vivaldi", "Web browser.", &["browsers"], None)];
+
+        let report = CatalogValidator::validate(&categories, &products);
+        assert!(report.errors.is_empty());
+        assert_eq!(report.warnings, ["Product 'Vivaldi' has no logo"]);
+    }
+
+    #[test]
+    fn products_referencing_unknown_categories_are_reported() {
+        let categories = [category("browsers", "Browsers", "Browse the web.")];
+        let products = [
+            product("Vivaldi", "Web browser.", &["browsers"], Some("vivaldi")),
+            product("Orphan", "No category.", &["browsers", "removed-category"], None),
+        ];
+        let category_slug_to_index = CatalogIndexMaps::build_category_slug_index(&categories);
+
+        let unmapped = CatalogIndexMaps::find_unmapped_categories(&products, &category_slug_to_index);
+        assert_eq!(unmapped, [("Orphan", vec!["removed-category"])]);
+    }
+
+    #[test]
+    fn unknown_categories_are_left_out_of_generated_code() {
+        let categories = [
+            category("browsers", "Browsers", "Browse the web."),
+            category("email-providers", "Email Providers", "Send email."),
+        ];
+        let products = [
+            product("Vivaldi", "Web browser.", &["removed-category", "email-providers", "browsers"], None),
+        ];
+
+        let index_maps = CatalogIndexMaps::build_from_catalog(&categories, &products);
+        let code = CatalogCodeBuilder::build_catalog_struct_code(&categories, &products, &index_maps);
+
+        assert!(code.contains("categories: &[1, 0]"), "{code}");
+        assert_eq!(index_maps.products_by_category_index, [vec![0], vec![0]]);
+    }
+
+    #[test]
+    fn normalized_urls_drop_queries_fragments_and_trailing_slashes() {
+        let base = "https://european-alternatives.eu/product/proton-mail";
+        assert_eq!(UrlBuilder::normalize_url(base), base);
+        assert_eq!(UrlBuilder::normalize_url(&format!("{base}/")), base);
+        assert_eq!(UrlBuilder::normalize_url(&format!("{base}?ref=home")), base);
+        assert_eq!(UrlBuilder::normalize_url(&format!("{base}#pricing")), base);
+        assert_eq!(UrlBuilder::normalize_url(&format!("{base}/?ref=home#pricing")), base);
+    }
+
+    #[test]
+    fn base_url_honours_the_configured_value() {
+        assert_eq!(UrlBuilder::resolve_base_url(None), DEFAULT_BASE_URL);
+        assert_eq!(UrlBuilder::resolve_base_url(Some("  ")), DEFAULT_BASE_URL);
+        assert_eq!(UrlBuilder::resolve_base_url(Some("http://localhost:8000/ ")), "http://localhost:8000");
+        assert_eq!(UrlBuilder::resolve_base_url(Some("https://mirror.example/eu")), "https://mirror.example/eu");
+    }
+
+    #[test]
+    fn retry_delays_double_and_saturate() {
+        let base_delay = Duration::from_millis(500);
+
+        assert_eq!(HttpClient::retry_delay(base_delay, 1), base_delay);
+        assert_eq!(HttpClient::retry_delay(base_delay, 3), Duration::from_secs(2));
+        assert_eq!(HttpClient::retry_delay(base_delay, 40), base_delay.saturating_mul(u32::MAX));
+        assert_eq!(HttpClient::retry_delay(Duration::MAX, 2), Duration::MAX);
+    }
+
+    #[test]
+    fn failed_requests_keep_the_transport_error() {
+        let RequestOutcome::Retryable(error) = HttpClient::send_single_request("http://127.0.0.1:9/") else {
+            panic!("a refused connection should be retried");
+        };
+
+        assert_eq!(error.to_string(), "Failed to send request to http://127.0.0.1:9/");
+        assert!(error.downcast_ref::<minreq::Error>().is_some());
+    }
+
+    #[test]
+    fn cache_keys_are_stable_fnv1a_hashes() {
+        assert_eq!(ScraperCache::cache_key(""), "cbf29ce484222325");
+        assert_eq!(ScraperCache::cache_key("a"), "af63dc4c8601ec8c");
+
+        let url = "https://european-alternatives.eu/category/browsers";
+        let key = ScraperCache::cache_key(url);
+        assert_eq!(key, ScraperCache::cache_key(url));
+        assert_eq!(key.len(), 16);
+        assert!(key.chars().all(|character| character.is_ascii_hexdigit()));
+        assert_ne!(key, ScraperCache::cache_key(&format!("{url}/")));
+    }
+
+    #[test]
+    fn generated_code_is_stamped_with_the_catalog_version() {
+        let index_maps = CatalogIndexMaps::build_from_catalog(&[], &[]);
+        let code = CatalogCodeBuilder::build_catalog_struct_code(&[], &[], &index_maps);
+        assert_eq!(CatalogVersion::from_code(&code), Some(Catalog::VERSION));
+    }
+
+    #[test]
+    fn code_without_a_leading_marker_has_no_version() {
+        assert_eq!(CatalogVersion::from_code("crate::models::Catalog { version: 3, }"), None);
+        assert_eq!(CatalogVersion::from_code("\n// catalog-version: 3"), None);
+        assert_eq!(CatalogVersion::from_code("// catalog-version: three"), None);
+    }
+
+    #[test]
+    fn generated_json_is_stamped_with_the_catalog_version() {
+        let json = CatalogJsonBuilder::build_catalog_json(&[], &[]);
+        assert_eq!(CatalogVersion::from_json(&json), Some(Catalog::VERSION));
+    }
+
+    #[test]
+    fn json_with_a_mismatched_version_is_rejected() {
+        let json = format!(
+            "{{\n  \"version\": {version},\n  \"categories\": []\n}}",
+            version = Catalog::VERSION + 1
+        );
+        assert_eq!(CatalogVersion::from_json(&json), Some(Catalog::VERSION + 1));
+        assert_eq!(
+            CatalogVersion::check(CatalogVersion::from_json(&json)).unwrap_err().to_string(),
+            format!(
+                "Catalog version mismatch: expected {expected}, found {found}",
+                expected = Catalog::VERSION,
+                found = Catalog::VERSION + 1
+            )
+        );
+
+        assert_eq!(CatalogVersion::from_json("{\"categories\": [], \"version\": 3}"), None);
+        assert_eq!(
+            CatalogVersion::check(CatalogVersion::from_json("[]")).unwrap_err().to_string(),
+            format!("Catalog version mismatch: expected {expected}, found no version", expected = Catalog::VERSION)
+        );
+        assert!(CatalogVersion::check(Some(Catalog::VERSION)).is_ok());
+    }
+
+    #[test]
+    fn cached_files_report_their_version_mismatch() {
+        let directory = std::env::temp_dir().join(format!("catalog-version-{pid}", pid = std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("catalog.rs");
+
+        assert!(!CatalogVersion::check_file(&path, CatalogVersion::from_code).unwrap());
+
+        std::fs::write(&path, "// catalog-version: 1\n").unwrap();
+        let error = CatalogVersion::check_file(&path, CatalogVersion::from_code).unwrap_err();
+        assert_eq!(
+            format!("{error:#}"),
+            format!(
+                "Cached catalog {path} is stale: Catalog version mismatch: expected {expected}, found 1",
+                path = path.display(),
+                expected = Catalog::VERSION
+            )
+        );
+
+        std::fs::write(&path, CatalogVersion::code_marker()).unwrap();
+        assert!(CatalogVersion::check_file(&path, CatalogVersion::from_code).unwrap());
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    // Extraction reads the shared fixtures directory, so tests that run it take turns.
+    static EXTRACTION: Mutex<()> = Mutex::new(());
+
+    fn extract_fixture_catalog(name: &str) -> Result<(Vec<Category>, Vec<Product>, Vec<Icon>)> {
+        let _extraction = EXTRACTION.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures").join(name);
+        *FIXTURES_DIRECTORY.lock().unwrap() = Some(directory);
+
+        let (mut categories, mut products, icons) = CatalogExtractor::extract_complete_catalog()?;
+        categories.sort_by(|left, right| left.slug.cmp(&right.slug));
+        products.sort_by(|left, right| left.slug.cmp(&right.slug));
+        for product in &mut products {
+            product.categories.sort();
+        }
+
+        Ok((categories, products, icons))
+    }
+
+    #[test]
+    fn fixtures_map_url_paths_to_html_files() {
+        let directory = Path::new("fixtures");
+        let base = "https://european-alternatives.eu";
+        assert_eq!(CatalogFixtures::file_path(directory, base), directory.join("index.html"));
+        assert_eq!(
+            CatalogFixtures::file_path(directory, &format!("{base}/category/browsers/?page=2")),
+            directory.join("category/browsers.html")
+        );
+        assert_eq!(
+            CatalogFixtures::file_path(directory, &format!("{base}/productLogo/vivaldi.svg")),
+            directory.join("productLogo/vivaldi.svg")
+        );
+    }
+
+    #[test]
+    fn fixtures_produce_a_known_catalog() {
+        let (categories, products, icons) = extract_fixture_catalog("catalog").unwrap();
+
+        let category_fields = categories
+            .iter()
+            .map(|category| {
+                (category.slug.as_str(), category.name.as_str(), category.summary.as_str())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(category_fields, [
+            ("browsers", "Browsers", "Browsers developed in Europe."),
+            ("email-providers", "Email Providers", "Email services hosted in Europe."),
+        ]);
+        assert_eq!(categories[0].icon, "browsers");
+
+        let [proton_mail, vivaldi] = products.as_slice() else {
+            panic!("Expected two products, found {count}", count = products.len());
+        };
+
+        assert_eq!(proton_mail.name, "Proton Mail");
+        assert_eq!(proton_mail.categories, ["email-providers"]);
+        assert_eq!(proton_mail.country, Some(Country::Switzerland));
+        assert_eq!(proton_mail.logo, None);
+        assert_eq!(proton_mail.summary, "Encrypted email.");
+        assert_eq!(proton_mail.websites, [(
+            String::from("European Alternatives"),
+            String::from("https://european-alternatives.eu/product/proton-mail"),
+        )]);
+
+        assert_eq!(vivaldi.name, "Vivaldi");
+        assert_eq!(vivaldi.categories, ["browsers"]);
+        assert_eq!(vivaldi.country, None);
+        assert_eq!(vivaldi.logo.as_deref(), Some("vivaldi"));
+        assert_eq!(
+            vivaldi.description,
+            "Vivaldi is a web browser. It has many features. It is made in Europe.\n\nIt is built on Chromium."
+        );
+        assert_eq!(vivaldi.websites, [
+            (String::from("Company"), String::from("https://vivaldi.com")),
+            (
+                String::from("European Alternatives"),
+                String::from("https://european-alternatives.eu/product/vivaldi"),
+            ),
+        ]);
+
+        let report = CatalogValidator::validate(&categories, &products);
+        assert!(report.errors.is_empty());
+        assert_eq!(report.warnings, ["Product 'Proton Mail' has no logo"]);
+
+        let icon_names = icons.iter().map(|icon| icon.name.as_str()).collect::<HashSet<_>>();
+        assert!(icon_names.is_superset(&HashSet::from(["browsers", "email_providers", "vivaldi"])));
+        assert_eq!(icons.len(), 3 + Country::COUNT);
+    }
+
+    #[test]
+    fn fixtures_without_categories_produce_an_empty_catalog() {
+        let (categories, products, icons) = extract_fixture_catalog("empty-catalog").unwrap();
+        assert!(categories.is_empty());
+        assert!(products.is_empty());
+        assert_eq!(icons.len(), Country::COUNT);
+
+        let index_maps = CatalogIndexMaps::build_from_catalog(&categories, &products);
+        let code =
+            CatalogCodeBuilder::build_catalog_struct_code(&categories, &products, &index_maps);
+        assert_eq!(CatalogVersion::from_code(&code), Some(Catalog::VERSION));
+    }
+
+    #[test]
+    fn fixture_builds_bundle_every_referenced_icon() {
+        let (categories, products, icons) = extract_fixture_catalog("catalog").unwrap();
+        let _extraction = EXTRACTION.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        let directory = std::env::temp_dir().join(format!("fixture-icons-{pid}", pid = std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+
+        let mut harvester = IconHarvester::new();
+        let resources_xml = harvester
+            .register_icons(&icons)
+            .download_all_to_directory(&directory)
+            .unwrap()
+            .build_resources_xml();
+
+        let referenced_icons = products
+            .iter()
+            .filter_map(|product| product.logo.as_deref())
+            .chain(categories.iter().map(|category| category.slug.as_str()))
+            .map(|name| heck::AsSnakeCase(name).to_string())
+            .chain(Country::all().iter().map(|country| heck::AsSnakeCase(country.slug()).to_string()));
+        for name in referenced_icons {
+            let icon = icons.iter().find(|icon| icon.name == name).unwrap_or_else(|| panic!("no icon for {name}"));
+            assert!(directory.join(&icon.filename).is_file(), "{filename}", filename = icon.filename);
+            assert!(resources_xml.contains(&format!("alias=\"{filename}\"", filename = icon.filename)));
+        }
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn missing_fixtures_are_reported() {
+        let error = extract_fixture_catalog("missing").unwrap_err().to_string();
+        assert!(error.starts_with("Missing fixture"), "{error}");
+    }
+}
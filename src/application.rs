@@ -2,25 +2,42 @@ use super::prelude::*;
 use super::constants;
 use super::widgets::WindowWidget;
 use super::ui::Ui;
-use super::repository::Repository;
+use super::repository::{ProductSort, Repository};
 use super::populator::Populator;
-use super::search_engine::SearchEngine;
+use super::search_engine::{SearchEngine, DEFAULT_CACHE_CAPACITY};
+use super::settings::SettingsStore;
+use super::deep_link::{DeepLink, DeepLinkTarget};
 use super::controllers::{
     SearchController,
     ProductActivationController,
     ProductRowActivationController,
     WindowSizeController,
+    WindowTitleController,
+    ExportController,
+    GlobalSearchController,
+    LastCategoryController,
+    FavoritesController,
+    PreferencesController,
+    ComparisonController,
+    RecentsController,
+    ColorScheme,
     ActionsController
 };
 
-use std::cell::OnceCell;
+use std::cell::{Cell, OnceCell, RefCell};
+
+const ANIMATE_ROW_REVEAL_KEY: &str = "animate-row-reveal";
+const CATEGORY_COLORS_KEY: &str = "category-colors";
+const PRODUCT_SORT_KEY: &str = "product-sort";
+const SEARCH_CACHE_CAPACITY_KEY: &str = "search-cache-capacity";
+const PRODUCT_OPTION: &str = "product";
+const CATEGORY_OPTION: &str = "category";
 
 mod implementation {
     use super::*;
 
     pub struct Application {
-        #[cfg(schemas_installed)]
-        pub settings: gtk::gio::Settings,
+        pub settings: SettingsStore,
 
         pub repository: Repository,
         pub ui: OnceCell<Ui>,
@@ -28,14 +45,23 @@ mod implementation {
         pub product_activation_controller: OnceCell<ProductActivationController>,
         pub product_row_activation_controller: OnceCell<ProductRowActivationController>,
         pub window_size_controller: OnceCell<WindowSizeController>,
+        pub window_title_controller: OnceCell<WindowTitleController>,
+        pub export_controller: OnceCell<ExportController>,
+        pub global_search_controller: OnceCell<GlobalSearchController>,
+        pub last_category_controller: OnceCell<LastCategoryController>,
+        pub favorites_controller: OnceCell<FavoritesController>,
+        pub preferences_controller: OnceCell<PreferencesController>,
+        pub comparison_controller: OnceCell<ComparisonController>,
+        pub recents_controller: OnceCell<RecentsController>,
         pub actions_controller: OnceCell<ActionsController>,
+        pub pending_deep_link: Cell<Option<DeepLink>>,
+        pub deep_link_error: RefCell<Option<String>>,
     }
 
     impl Default for Application {
         fn default() -> Self {
             Self {
-                #[cfg(schemas_installed)]
-                settings: gtk::gio::Settings::new(constants::APP_ID),
+                settings: SettingsStore::new(),
 
                 repository: Repository::new(&constants::APP_CATALOG),
                 ui: OnceCell::new(),
@@ -43,7 +69,17 @@ mod implementation {
                 product_activation_controller: OnceCell::new(),
                 product_row_activation_controller: OnceCell::new(),
                 window_size_controller: OnceCell::new(),
+                window_title_controller: OnceCell::new(),
+                export_controller: OnceCell::new(),
+                global_search_controller: OnceCell::new(),
+                last_category_controller: OnceCell::new(),
+                favorites_controller: OnceCell::new(),
+                preferences_controller: OnceCell::new(),
+                comparison_controller: OnceCell::new(),
+                recents_controller: OnceCell::new(),
                 actions_controller: OnceCell::new(),
+                pending_deep_link: Cell::new(None),
+                deep_link_error: RefCell::new(None),
             }
         }
     }
@@ -60,12 +96,44 @@ mod implementation {
     impl ApplicationImpl for Application {
         fn activate(&self) {
             self.parent_activate();
+            if let Some(ui) = self.ui.get() {
+                ui.window().present();
+                self.obj().open_pending_deep_link();
+                return;
+            }
+
             self.obj().setup_activation();
         }
 
+        fn command_line(&self, command_line: &gtk::gio::ApplicationCommandLine) -> glib::ExitCode {
+            // Positional arguments are links, which are routed through `open`.
+            let files = command_line
+                .arguments()
+                .iter()
+                .skip(1)
+                .map(|argument| command_line.create_file_for_arg(argument))
+                .collect::<Vec<_>>();
+            if !files.is_empty() {
+                self.obj().open(&files, "");
+                return glib::ExitCode::SUCCESS;
+            }
+
+            self.obj().handle_command_line_options(&command_line.options_dict());
+            self.obj().activate();
+            glib::ExitCode::SUCCESS
+        }
+
+        fn open(&self, files: &[gtk::gio::File], _hint: &str) {
+            if let Some(file) = files.first() {
+                self.obj().handle_uri(&file.uri());
+            }
+            self.obj().activate();
+        }
+
         fn startup(&self) {
             self.parent_startup();
             super::Application::setup_startup();
+            ColorScheme::load(&self.settings).apply();
         }
     }
 
@@ -84,21 +152,96 @@ impl Default for Application {
         glib::Object::builder()
             .property("application-id", constants::APP_ID)
             .property("resource-base-path", constants::APP_PREFIX)
-            .property("flags", gtk::gio::ApplicationFlags::default())
+            .property("flags", gtk::gio::ApplicationFlags::HANDLES_COMMAND_LINE | gtk::gio::ApplicationFlags::HANDLES_OPEN)
             .build()
     }
 }
 
 impl Application {
     pub fn new() -> Self {
-        Self::default()
+        let this = Self::default();
+        this.setup_command_line_options();
+        this
+    }
+
+    fn setup_command_line_options(&self) {
+        self.add_main_option(
+            PRODUCT_OPTION,
+            glib::Char::from(b'p'),
+            glib::OptionFlags::NONE,
+            glib::OptionArg::String,
+            "Open the product with the given name",
+            Some("NAME"),
+        );
+        self.add_main_option(
+            CATEGORY_OPTION,
+            glib::Char::from(b'c'),
+            glib::OptionFlags::NONE,
+            glib::OptionArg::String,
+            "Open the category with the given slug",
+            Some("SLUG"),
+        );
+    }
+
+    fn handle_command_line_options(&self, options: &glib::VariantDict) {
+        let repository = self.imp().repository;
+        if let Ok(Some(name)) = options.lookup::<String>(PRODUCT_OPTION) {
+            self.set_deep_link(DeepLink::product(repository, &name), &name);
+        } else if let Ok(Some(slug)) = options.lookup::<String>(CATEGORY_OPTION) {
+            self.set_deep_link(DeepLink::category(repository, &slug), &slug);
+        }
+    }
+
+    fn handle_uri(&self, uri: &str) {
+        let unescaped = glib::Uri::unescape_string(uri, None::<&str>)
+            .map_or_else(|| uri.to_string(), |uri| uri.to_string());
+        match DeepLinkTarget::parse_uri(&unescaped) {
+            Some(target) => self.set_deep_link(target.resolve(self.imp().repository), &unescaped),
+            None => {
+                self.imp().pending_deep_link.set(None);
+                self.imp().deep_link_error.replace(Some(format!("Invalid link “{unescaped}”")));
+            }
+        }
+    }
+
+    fn set_deep_link(&self, deep_link: Option<DeepLink>, requested: &str) {
+        self.imp().pending_deep_link.set(deep_link);
+        if deep_link.is_none() {
+            self.imp().deep_link_error.replace(Some(format!("Nothing found for “{requested}”")));
+        }
+    }
+
+    // Deep links are kept until the catalog has been populated, so a link that
+    // arrives before the first activation is opened right after it.
+    fn open_pending_deep_link(&self) {
+        let Some(ui) = self.imp().ui.get() else {
+            return;
+        };
+
+        if let Some(message) = self.imp().deep_link_error.take() {
+            ui.window().notify(&message);
+        }
+
+        match self.imp().pending_deep_link.take() {
+            Some(DeepLink::Product(product_index)) => {
+                if let Some(product_activation_controller) = self.imp().product_activation_controller.get() {
+                    product_activation_controller.open_product(product_index);
+                }
+            }
+            Some(DeepLink::Category(category_index)) => {
+                let ui = ui.clone();
+                glib::idle_add_local_once(move || {
+                    ui.overview_page().scroll_to_group_index(category_index);
+                });
+            }
+            None => {}
+        }
     }
 
     fn setup_startup() {
         glib::set_application_name(constants::APP_TITLE);
         glib::set_prgname(Some(constants::APP_NAME));
         gtk::gio::resources_register_include_impl(constants::APP_RESOURCES).unwrap();
-        adw::StyleManager::default().set_color_scheme(adw::ColorScheme::PreferDark);
 
         let css_provider = gtk::CssProvider::new();
         css_provider.load_from_resource(&format!("{prefix}/style.css", prefix = constants::APP_PREFIX));
@@ -114,33 +257,90 @@ impl Application {
     fn setup_activation(&self) {
         let window = WindowWidget::new(self, constants::APP_TITLE);
         let ui = Ui::new(window);
-        Populator::populate(&ui, self.imp().repository);
+        let product_sort_key = self.imp().settings.string(PRODUCT_SORT_KEY, ProductSort::default().key());
+        let product_sort = ProductSort::from_key(&product_sort_key).unwrap_or_default();
+        let populated = Populator::populate(&ui, self.imp().repository, product_sort);
 
         self.setup_controllers(&ui);
 
-        ui.activate();
+        if populated {
+            ui.activate();
+        }
         self.imp().ui.set(ui).ok().unwrap();
+
+        if populated {
+            self.open_pending_deep_link();
+        }
     }
 
     fn setup_controllers(&self, ui: &Ui) {
         let repository = self.imp().repository;
 
-        let search_controller = SearchController::new(ui.clone(), SearchEngine::new(repository));
-        let product_activation_controller = ProductActivationController::new(ui.clone(), repository);
+        let search_engine = SearchEngine::new(repository);
+        // A missing or negative value keeps the default capacity.
+        let cache_capacity = self.imp().settings.int(SEARCH_CACHE_CAPACITY_KEY, -1);
+        search_engine.set_cache_capacity(usize::try_from(cache_capacity).unwrap_or(DEFAULT_CACHE_CAPACITY));
+        let search_controller = SearchController::new(ui.clone(), search_engine.clone(), self.imp().settings.clone());
+        let product_activation_controller = ProductActivationController::new(ui.clone(), repository, self.imp().settings.clone());
         let product_row_activation_controller = ProductRowActivationController::new(ui.clone(), repository);
+        let window_title_controller = WindowTitleController::new(ui.clone(), repository);
+
+        let favorites_controller = FavoritesController::new(ui.clone(), repository, self.imp().settings.clone());
+        let recents_controller = RecentsController::new(ui.clone(), repository, self.imp().settings.clone());
+
+        let export_controller = ExportController::new(ui.clone(), repository);
+        let global_search_controller = GlobalSearchController::new(
+            ui.clone(),
+            repository,
+            search_engine,
+            product_activation_controller.clone(),
+            favorites_controller.clone(),
+            recents_controller.clone()
+        );
 
-        let actions_controller = ActionsController::new(self.clone(), search_controller.clone());
+        let preferences_controller = PreferencesController::new(
+            ui.clone(),
+            self.imp().settings.clone(),
+            search_controller.clone()
+        );
+
+        let actions_controller = ActionsController::new(
+            self.clone(),
+            repository,
+            search_controller.clone(),
+            export_controller.clone(),
+            global_search_controller.clone(),
+            preferences_controller.clone()
+        );
 
         self.imp().search_controller.set(search_controller).ok().unwrap();
         self.imp().product_activation_controller.set(product_activation_controller).ok().unwrap();
         self.imp().product_row_activation_controller.set(product_row_activation_controller).ok().unwrap();
+        self.imp().window_title_controller.set(window_title_controller).ok().unwrap();
+        self.imp().export_controller.set(export_controller).ok().unwrap();
+        self.imp().global_search_controller.set(global_search_controller).ok().unwrap();
+        self.imp().preferences_controller.set(preferences_controller).ok().unwrap();
         self.imp().actions_controller.set(actions_controller).ok().unwrap();
 
-        #[cfg(schemas_installed)]
-        {
-            let window_size_controller = WindowSizeController::new(ui.clone(), self.imp().settings.clone());
-            self.imp().window_size_controller.set(window_size_controller).ok().unwrap();
-        }
+        let window_size_controller = WindowSizeController::new(ui.clone(), self.imp().settings.clone());
+        self.imp().window_size_controller.set(window_size_controller).ok().unwrap();
+
+        self.imp().favorites_controller.set(favorites_controller).ok().unwrap();
+
+        self.imp().recents_controller.set(recents_controller).ok().unwrap();
+
+        let comparison_controller = ComparisonController::new(ui.clone(), repository);
+        self.imp().comparison_controller.set(comparison_controller).ok().unwrap();
+
+        let last_category_controller = LastCategoryController::new(ui.clone(), repository, self.imp().settings.clone());
+        self.imp().last_category_controller.set(last_category_controller).ok().unwrap();
+
+        let animate_row_reveal = self.imp().settings.boolean(ANIMATE_ROW_REVEAL_KEY, false);
+        ui.overview_page().set_animate_row_reveal(animate_row_reveal);
+
+        let category_colors = self.imp().settings.boolean(CATEGORY_COLORS_KEY, false);
+        ui.overview_page().set_category_colors(category_colors);
+        ui.category_list().set_category_colors(category_colors);
     }
 
     pub fn run(&self) -> Result<()> {
@@ -2,7 +2,7 @@
 
 use super::models::Catalog;
 
-pub const APP_CATALOG: Catalog = include!(env!("APP_CATALOG"));
+pub static APP_CATALOG: Catalog = include!(env!("APP_CATALOG"));
 pub const APP_RESOURCES: &[u8] = include_bytes!(env!("APP_RESOURCES"));
 pub const APP_ID: &str = env!("APP_ID");
 pub const APP_NAME: &str = env!("APP_NAME");
@@ -12,5 +12,7 @@ pub const APP_UI_RESOURCE: &str = concat!(env!("APP_PREFIX"), "/ui.xml");
 pub const APP_TITLE: &str = env!("APP_TITLE");
 pub const APP_DESCRIPTION: &str = env!("APP_DESCRIPTION");
 pub const APP_AUTHORS: &str = env!("APP_AUTHORS");
+pub const APP_SOURCE_URL: &str = env!("APP_SOURCE_URL");
+pub const APP_SOURCE_NAME: &str = "European Alternatives";
 
 pub const GSETTINGS_SCHEMA_DIR: Option<&str> = option_env!("GSETTINGS_SCHEMA_DIR");
\ No newline at end of file
@@ -1,17 +1,45 @@
 use super::super::prelude::*;
-use super::super::controllers::SearchController;
+use super::super::constants;
+use super::super::controllers::{SearchController, ExportController, GlobalSearchController, PreferencesController};
 use super::super::application::Application;
+use super::super::repository::{CatalogStats, Repository};
 
 pub struct Actions {
     application: Application,
+    repository: Repository,
     search_controller: SearchController,
+    export_controller: ExportController,
+    global_search_controller: GlobalSearchController,
+    preferences_controller: PreferencesController,
 }
 
 impl Actions {
-    pub fn new(application: Application, search_controller: SearchController) -> Self {
-        let this = Self { application, search_controller };
+    pub fn new(
+        application: Application,
+        repository: Repository,
+        search_controller: SearchController,
+        export_controller: ExportController,
+        global_search_controller: GlobalSearchController,
+        preferences_controller: PreferencesController
+    ) -> Self {
+        let this = Self {
+            application,
+            repository,
+            search_controller,
+            export_controller,
+            global_search_controller,
+            preferences_controller
+        };
         this.setup_quit_action();
         this.setup_search_action();
+        this.setup_filter_country_action();
+        this.setup_clear_filters_action();
+        this.setup_back_action();
+        this.setup_global_search_action();
+        this.setup_about_action();
+        this.setup_export_action();
+        this.setup_copy_visible_action();
+        this.setup_preferences_action();
         this
     }
 
@@ -47,4 +75,238 @@ impl Actions {
         });
     }
 
-}
\ No newline at end of file
+    fn setup_filter_country_action(&self) {
+        let filter_country_action = gtk::gio::SimpleAction::new("filter-country", None);
+        self.connect_filter_country_handler(&filter_country_action);
+        self.application.add_action(&filter_country_action);
+        self.application.set_accels_for_action("app.filter-country", &["<Ctrl>f"]);
+    }
+
+    fn connect_filter_country_handler(&self, filter_country_action: &gtk::gio::SimpleAction) {
+        let search_controller_weak = self.search_controller.downgrade();
+        filter_country_action.connect_activate(move |_action, _| {
+            if let Some(search_controller) = search_controller_weak.upgrade() {
+                search_controller.activate_country_filter();
+            }
+        });
+    }
+
+    fn setup_clear_filters_action(&self) {
+        let clear_filters_action = gtk::gio::SimpleAction::new("clear-filters", None);
+        self.connect_clear_filters_handler(&clear_filters_action);
+        self.application.add_action(&clear_filters_action);
+    }
+
+    fn connect_clear_filters_handler(&self, clear_filters_action: &gtk::gio::SimpleAction) {
+        let search_controller_weak = self.search_controller.downgrade();
+        clear_filters_action.connect_activate(move |_action, _| {
+            if let Some(search_controller) = search_controller_weak.upgrade() {
+                search_controller.clear_filters();
+            }
+        });
+    }
+
+    fn setup_back_action(&self) {
+        let back_action = gtk::gio::SimpleAction::new("back", None);
+        self.connect_back_handler(&back_action);
+        self.application.add_action(&back_action);
+        self.application.set_accels_for_action("app.back", &["<Alt>Left"]);
+    }
+
+    fn connect_back_handler(&self, back_action: &gtk::gio::SimpleAction) {
+        let search_controller_weak = self.search_controller.downgrade();
+        back_action.connect_activate(move |_action, _| {
+            if let Some(search_controller) = search_controller_weak.upgrade() {
+                search_controller.navigate_back();
+            }
+        });
+    }
+
+    fn setup_global_search_action(&self) {
+        let global_search_action = gtk::gio::SimpleAction::new("global-search", None);
+        self.connect_global_search_handler(&global_search_action);
+        self.application.add_action(&global_search_action);
+        self.application.set_accels_for_action("app.global-search", &["<Ctrl>k"]);
+    }
+
+    fn connect_global_search_handler(&self, global_search_action: &gtk::gio::SimpleAction) {
+        let global_search_controller_weak = self.global_search_controller.downgrade();
+        global_search_action.connect_activate(move |_action, _| {
+            if let Some(global_search_controller) = global_search_controller_weak.upgrade() {
+                global_search_controller.activate();
+            }
+        });
+    }
+
+    fn setup_export_action(&self) {
+        let export_action = gtk::gio::SimpleAction::new("export", None);
+        self.connect_export_handler(&export_action);
+        self.application.add_action(&export_action);
+        self.application.set_accels_for_action("app.export", &["<Ctrl>e"]);
+    }
+
+    fn connect_export_handler(&self, export_action: &gtk::gio::SimpleAction) {
+        let export_controller_weak = self.export_controller.downgrade();
+        export_action.connect_activate(move |_action, _| {
+            if let Some(export_controller) = export_controller_weak.upgrade() {
+                export_controller.export();
+            }
+        });
+    }
+
+    fn setup_copy_visible_action(&self) {
+        let copy_visible_action = gtk::gio::SimpleAction::new("copy-visible", None);
+        self.connect_copy_visible_handler(&copy_visible_action);
+        self.application.add_action(&copy_visible_action);
+        self.application.set_accels_for_action("app.copy-visible", &["<Ctrl><Shift>c"]);
+    }
+
+    fn connect_copy_visible_handler(&self, copy_visible_action: &gtk::gio::SimpleAction) {
+        let export_controller_weak = self.export_controller.downgrade();
+        copy_visible_action.connect_activate(move |_action, _| {
+            if let Some(export_controller) = export_controller_weak.upgrade() {
+                export_controller.copy_visible_as_markdown();
+            }
+        });
+    }
+
+    fn setup_preferences_action(&self) {
+        let preferences_action = gtk::gio::SimpleAction::new("preferences", None);
+        self.connect_preferences_handler(&preferences_action);
+        self.application.add_action(&preferences_action);
+        self.application.set_accels_for_action("app.preferences", &["<Ctrl>comma"]);
+    }
+
+    fn connect_preferences_handler(&self, preferences_action: &gtk::gio::SimpleAction) {
+        let preferences_controller_weak = self.preferences_controller.downgrade();
+        preferences_action.connect_activate(move |_action, _| {
+            if let Some(preferences_controller) = preferences_controller_weak.upgrade() {
+                preferences_controller.present();
+            }
+        });
+    }
+
+    fn setup_about_action(&self) {
+        let about_action = gtk::gio::SimpleAction::new("about", None);
+        self.connect_about_handler(&about_action);
+        self.application.add_action(&about_action);
+        self.application.set_accels_for_action("app.about", &["<Ctrl>i"]);
+    }
+
+    fn connect_about_handler(&self, about_action: &gtk::gio::SimpleAction) {
+        let application_weak = self.application.downgrade();
+        let repository = self.repository;
+        about_action.connect_activate(move |_action, _| {
+            if let Some(application) = application_weak.upgrade() {
+                Self::present_about_dialog(&application, repository);
+            }
+        });
+    }
+
+    fn present_about_dialog(application: &Application, repository: Repository) {
+        let authors = Self::authors();
+        let comments = format!(
+            "{description}\n\n{summary} {coverage}",
+            description = constants::APP_DESCRIPTION,
+            summary = Self::catalog_summary(&repository.stats()),
+            coverage = Self::country_coverage_summary(repository.country_coverage())
+        );
+        let developer_name = authors.first().map_or("", |author| Self::author_name(author));
+        let dialog = adw::AboutDialog::builder()
+            .application_name(constants::APP_TITLE)
+            .application_icon(constants::APP_ID)
+            .version(constants::APP_VERSION)
+            .comments(comments)
+            .developer_name(developer_name)
+            .developers(authors.as_slice())
+            .build();
+
+        dialog.add_credit_section(Some("Data Source"), &[&Self::attribution_credit()]);
+        dialog.connect_activate_link(|dialog, uri| {
+            gtk::UriLauncher::new(uri).launch(
+                dialog.root().and_downcast_ref::<gtk::Window>(),
+                None::<&gtk::gio::Cancellable>,
+                |_| {}
+            );
+            true
+        });
+
+        dialog.present(application.active_window().as_ref());
+    }
+
+    /// Authors are joined with "," at build time, each possibly carrying an
+    /// `<email>` suffix which the dialog turns into a link.
+    pub fn authors() -> Vec<&'static str> {
+        constants::APP_AUTHORS
+            .split(',')
+            .map(str::trim)
+            .filter(|author| !author.is_empty())
+            .collect()
+    }
+
+    fn author_name(author: &str) -> &str {
+        author.split_once('<').map_or(author, |(name, _)| name).trim()
+    }
+
+    pub fn catalog_summary(stats: &CatalogStats) -> String {
+        format!(
+            "Browse {products} alternatives across {categories} categories from {countries} countries.",
+            products = stats.total_products,
+            categories = stats.total_categories,
+            countries = stats.countries_represented
+        )
+    }
+
+    pub fn country_coverage_summary((with_country, total): (usize, usize)) -> String {
+        format!("{with_country} of {total} list their country of origin.")
+    }
+
+    pub fn attribution_url() -> &'static str {
+        constants::APP_SOURCE_URL
+    }
+
+    fn attribution_credit() -> String {
+        format!("{name} {url}", name = constants::APP_SOURCE_NAME, url = Self::attribution_url())
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_catalog;
+
+    #[test]
+    fn catalog_summary_counts_the_catalog() {
+        let stats = CatalogStats {
+            total_products: 210,
+            total_categories: 34,
+            countries_represented: 20,
+            average_products_per_category: 6.2,
+        };
+
+        assert_eq!(
+            Actions::catalog_summary(&stats),
+            "Browse 210 alternatives across 34 categories from 20 countries."
+        );
+    }
+
+    #[test]
+    fn country_coverage_summary_counts_products_with_a_country() {
+        assert_eq!(
+            Actions::country_coverage_summary(test_catalog::repository().country_coverage()),
+            "3 of 4 list their country of origin."
+        );
+    }
+
+    #[test]
+    fn attribution_links_the_source_catalog() {
+        let url = Actions::attribution_url();
+        assert!(url.starts_with("https://") || url.starts_with("http://"), "{url}");
+        assert!(!url.ends_with('/'), "{url}");
+        assert_eq!(
+            Actions::attribution_credit(),
+            format!("{name} {url}", name = constants::APP_SOURCE_NAME)
+        );
+    }
+}
@@ -0,0 +1,238 @@
+use super::super::prelude::*;
+use super::super::repository::Repository;
+use super::super::widgets::{NavigationPage, OverviewProductRowWidget};
+use super::super::ui::Ui;
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+pub const MIN_COMPARED_PRODUCTS: usize = 2;
+pub const MAX_COMPARED_PRODUCTS: usize = 3;
+
+/// What the comparison page shows for one product, in selection order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComparisonColumn {
+    pub name: &'static str,
+    pub country: Option<&'static str>,
+    pub categories: Vec<&'static str>,
+    pub websites: Vec<&'static str>,
+}
+
+struct State {
+    ui: Ui,
+    repository: Repository,
+    selected: RefCell<Vec<usize>>
+}
+
+pub struct WeakComparison {
+    state: Weak<State>
+}
+
+impl WeakComparison {
+    pub fn upgrade(&self) -> Option<Comparison> {
+        self.state.upgrade().map(|state| Comparison { state })
+    }
+}
+
+#[derive(Clone)]
+pub struct Comparison {
+    state: Rc<State>
+}
+
+impl Comparison {
+
+    pub fn new(ui: Ui, repository: Repository) -> Self {
+        let state = State {
+            ui,
+            repository,
+            selected: RefCell::new(Vec::new())
+        };
+        let this = Self { state: Rc::new(state) };
+        this.setup_compare_toggled();
+        this.setup_compare_mode_changed();
+        this.setup_compare_requested();
+        this
+    }
+
+    fn setup_compare_toggled(&self) {
+        for group in self.state.ui.overview_page().groups().values() {
+            for row in group.rows().values() {
+                let this_weak = self.downgrade();
+                row.connect_compare_toggled(move |row, compared| {
+                    if let Some(this) = this_weak.upgrade() {
+                        this.handle_compare_toggled(row, compared);
+                    }
+                });
+            }
+        }
+    }
+
+    fn setup_compare_mode_changed(&self) {
+        let this_weak = self.downgrade();
+        self.state.ui.overview_page().connect_compare_mode_notify(move |overview_page| {
+            if
+                let Some(this) = this_weak.upgrade()
+                && !overview_page.compare_mode()
+            {
+                this.clear_selection();
+            }
+        });
+    }
+
+    fn setup_compare_requested(&self) {
+        let this_weak = self.downgrade();
+        self.state.ui.overview_page().connect_compare_requested(move |_| {
+            if let Some(this) = this_weak.upgrade() {
+                this.show_comparison();
+            }
+        });
+    }
+
+    fn handle_compare_toggled(&self, row: &OverviewProductRowWidget, compared: bool) {
+        let product_index = row.index() as usize;
+        let mut selected = self.state.selected.borrow_mut();
+
+        if !compared {
+            selected.retain(|&index| index != product_index);
+        } else if selected.len() >= MAX_COMPARED_PRODUCTS {
+            drop(selected);
+            row.set_compared(false);
+            self.state.ui.window().notify(&format!("Compare up to {MAX_COMPARED_PRODUCTS} products"));
+            return;
+        } else if !selected.contains(&product_index) {
+            selected.push(product_index);
+        }
+
+        drop(selected);
+        self.sync_rows(product_index, compared);
+        self.update_compare_ready();
+    }
+
+    /// Products in several categories have a row in each group, so all of them
+    /// follow the one that was toggled.
+    fn sync_rows(&self, product_index: usize, compared: bool) {
+        for group in self.state.ui.overview_page().groups().values() {
+            if let Some(row) = group.rows().get(&product_index) {
+                row.set_compared(compared);
+            }
+        }
+    }
+
+    fn update_compare_ready(&self) {
+        let count = self.state.selected.borrow().len();
+        self.state.ui.overview_page().set_compare_ready(Self::is_ready(count));
+    }
+
+    pub const fn is_ready(count: usize) -> bool {
+        count >= MIN_COMPARED_PRODUCTS && count <= MAX_COMPARED_PRODUCTS
+    }
+
+    fn clear_selection(&self) {
+        let selected = self.state.selected.take();
+        for product_index in selected {
+            self.sync_rows(product_index, false);
+        }
+        self.update_compare_ready();
+    }
+
+    /// Indices that don't resolve to a product are skipped.
+    pub fn assemble(repository: Repository, product_indices: &[usize]) -> Vec<ComparisonColumn> {
+        product_indices
+            .iter()
+            .filter_map(|&product_index| {
+                let product = repository.product_by_index(product_index)?;
+                let categories = repository
+                    .product_categories(product_index)
+                    .into_iter()
+                    .map(|category| category.name)
+                    .collect();
+                let websites = product.websites.iter().map(|(_, url)| *url).collect();
+
+                Some(ComparisonColumn {
+                    name: product.name,
+                    country: product.country.map(|country| country.display_name()),
+                    categories,
+                    websites,
+                })
+            })
+            .collect()
+    }
+
+    fn show_comparison(&self) {
+        let columns = Self::assemble(self.state.repository, &self.state.selected.borrow());
+        if !Self::is_ready(columns.len()) {
+            return;
+        }
+
+        let comparison_page = self.state.ui.comparison_page();
+        let names = columns.iter().map(|column| column.name).collect::<Vec<_>>();
+        comparison_page.set_products(&names);
+        comparison_page.append_row(
+            "Country",
+            &columns.iter().map(|column| column.country.unwrap_or_default().to_string()).collect::<Vec<_>>()
+        );
+        comparison_page.append_row(
+            "Categories",
+            &columns.iter().map(|column| column.categories.join("\n")).collect::<Vec<_>>()
+        );
+        comparison_page.append_row(
+            "Websites",
+            &columns.iter().map(|column| column.websites.join("\n")).collect::<Vec<_>>()
+        );
+
+        self.state.ui.navigation().push_page(NavigationPage::Comparison);
+    }
+
+    pub fn downgrade(&self) -> WeakComparison {
+        let state = Rc::downgrade(&self.state);
+        WeakComparison { state }
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_catalog;
+
+    #[test]
+    fn columns_follow_the_selection_order() {
+        let columns = Comparison::assemble(test_catalog::repository(), &[2, 0]);
+        let names = columns.iter().map(|column| column.name).collect::<Vec<_>>();
+        assert_eq!(names, ["Vivaldi", "Proton Mail"]);
+    }
+
+    #[test]
+    fn column_lists_country_categories_and_websites() {
+        let columns = Comparison::assemble(test_catalog::repository(), &[0]);
+        assert_eq!(columns, [ComparisonColumn {
+            name: "Proton Mail",
+            country: Some("Switzerland"),
+            categories: vec!["Email Providers"],
+            websites: vec!["https://proton.me/mail", "https://european-alternatives.eu/product/proton-mail"],
+        }]);
+    }
+
+    #[test]
+    fn product_without_country_or_websites_has_empty_fields() {
+        let columns = Comparison::assemble(test_catalog::repository(), &[2]);
+        assert_eq!(columns[0].country, None);
+        assert_eq!(columns[0].categories, ["Browsers", "Email Providers"]);
+        assert!(columns[0].websites.is_empty());
+    }
+
+    #[test]
+    fn unknown_indices_are_skipped() {
+        let columns = Comparison::assemble(test_catalog::repository(), &[1, 99]);
+        assert_eq!(columns.len(), 1);
+        assert_eq!(columns[0].name, "Nextcloud");
+    }
+
+    #[test]
+    fn ready_between_two_and_three_products() {
+        assert!(!Comparison::is_ready(1));
+        assert!(Comparison::is_ready(MIN_COMPARED_PRODUCTS));
+        assert!(Comparison::is_ready(MAX_COMPARED_PRODUCTS));
+        assert!(!Comparison::is_ready(MAX_COMPARED_PRODUCTS + 1));
+    }
+}
\ No newline at end of file
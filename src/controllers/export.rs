@@ -0,0 +1,440 @@
+use super::super::prelude::*;
+use super::super::constants;
+use super::super::models::{Category, Product, escape_json};
+use super::super::repository::Repository;
+use super::super::ui::Ui;
+
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::rc::{Rc, Weak};
+
+const CANCEL_RESPONSE: &str = "cancel";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    Markdown,
+}
+
+impl ExportFormat {
+    pub const fn all() -> &'static [Self] {
+        &[Self::Csv, Self::Json, Self::Markdown]
+    }
+
+    pub const fn display_name(self) -> &'static str {
+        match self {
+            Self::Csv => "CSV",
+            Self::Json => "JSON",
+            Self::Markdown => "Markdown",
+        }
+    }
+
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+            Self::Markdown => "md",
+        }
+    }
+
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|format| format.extension().eq_ignore_ascii_case(extension))
+    }
+
+    /// Appends the format's extension when the chosen file name has none.
+    pub fn with_extension(self, mut path: PathBuf) -> PathBuf {
+        if path.extension().is_none() {
+            path.set_extension(self.extension());
+        }
+        path
+    }
+
+    pub fn serialize(self, repository: Repository) -> String {
+        match self {
+            Self::Csv => Self::serialize_csv(repository),
+            Self::Json => Self::serialize_json(repository),
+            Self::Markdown => Self::serialize_markdown(repository),
+        }
+    }
+
+    fn product_category_names(repository: Repository, product: &Product) -> Vec<&'static str> {
+        product.categories
+            .iter()
+            .filter_map(|&category_index| repository.category_by_index(category_index))
+            .map(|category| category.name)
+            .collect()
+    }
+
+    fn product_website(product: &Product) -> &'static str {
+        product.websites.first().map_or("", |(_, url)| *url)
+    }
+
+    fn escape_csv(value: &str) -> String {
+        if value.contains([',', '"', '\n', '\r']) {
+            format!("\"{escaped}\"", escaped = value.replace('"', "\"\""))
+        } else {
+            value.to_string()
+        }
+    }
+
+    fn escape_markdown(value: &str) -> String {
+        value.replace('[', "\\[").replace(']', "\\]")
+    }
+
+    fn serialize_csv(repository: Repository) -> String {
+        let mut output = String::from("name,country,categories,summary,website\n");
+
+        for product in repository.products() {
+            let country = product.country.map_or("", |country| country.display_name());
+            let categories = Self::product_category_names(repository, product).join("; ");
+            let fields = [product.name, country, categories.as_str(), product.summary, Self::product_website(product)];
+            let row = fields.iter().map(|field| Self::escape_csv(field)).collect::<Vec<_>>();
+            writeln!(output, "{row}", row = row.join(",")).unwrap();
+        }
+
+        output
+    }
+
+    fn serialize_json(repository: Repository) -> String {
+        let products = repository
+            .products()
+            .iter()
+            .map(|product| {
+                let country = product.country.map_or_else(
+                    || String::from("null"),
+                    |country| escape_json(country.display_name())
+                );
+                let categories = Self::product_category_names(repository, product)
+                    .iter()
+                    .map(|name| escape_json(name))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let websites = product.websites
+                    .iter()
+                    .map(|(caption, url)| {
+                        format!(
+                            "{{\"caption\": {caption}, \"url\": {url}}}",
+                            caption = escape_json(caption),
+                            url = escape_json(url)
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!(
+                    "  {{\"name\": {name}, \"country\": {country}, \"categories\": [{categories}], \"summary\": {summary}, \"websites\": [{websites}]}}",
+                    name = escape_json(product.name),
+                    summary = escape_json(product.summary)
+                )
+            })
+            .collect::<Vec<_>>();
+
+        format!("[\n{products}\n]\n", products = products.join(",\n"))
+    }
+
+    /// Markdown list of the given products under a heading per category, e.g.
+    /// `- [Signal](https://signal.org) — Switzerland`.
+    pub fn format_markdown_list(sections: &[(&Category, Vec<&Product>)]) -> String {
+        let mut output = String::new();
+
+        for (category, products) in sections {
+            if products.is_empty() {
+                continue;
+            }
+
+            if !output.is_empty() {
+                output.push('\n');
+            }
+            write!(output, "## {name}\n\n", name = Self::escape_markdown(category.name)).unwrap();
+            for product in products {
+                let name = Self::escape_markdown(product.name);
+                let website = Self::product_website(product);
+                if website.is_empty() {
+                    write!(output, "- {name}").unwrap();
+                } else {
+                    write!(output, "- [{name}]({website})").unwrap();
+                }
+
+                if let Some(country) = product.country {
+                    write!(output, " — {country}", country = country.display_name()).unwrap();
+                }
+                output.push('\n');
+            }
+        }
+
+        output
+    }
+
+    fn serialize_markdown(repository: Repository) -> String {
+        let mut output = format!("# {title}\n", title = constants::APP_TITLE);
+
+        for (_, category) in repository.categories_sorted() {
+            let Some(products) = repository.category_products_sorted(category) else {
+                continue;
+            };
+
+            write!(output, "\n## {name}\n\n", name = Self::escape_markdown(category.name)).unwrap();
+            for (_, product) in products {
+                let name = Self::escape_markdown(product.name);
+                let website = Self::product_website(product);
+                if website.is_empty() {
+                    write!(output, "- **{name}**").unwrap();
+                } else {
+                    write!(output, "- [{name}]({website})").unwrap();
+                }
+
+                if let Some(country) = product.country {
+                    write!(output, " ({country})").unwrap();
+                }
+                writeln!(output, " — {summary}", summary = product.summary).unwrap();
+            }
+        }
+
+        output
+    }
+}
+
+struct State {
+    ui: Ui,
+    repository: Repository
+}
+
+pub struct WeakExport {
+    state: Weak<State>
+}
+
+impl WeakExport {
+    pub fn upgrade(&self) -> Option<Export> {
+        self.state.upgrade().map(|state| Export { state })
+    }
+}
+
+#[derive(Clone)]
+pub struct Export {
+    state: Rc<State>
+}
+
+impl Export {
+
+    pub fn new(ui: Ui, repository: Repository) -> Self {
+        let state = State { ui, repository };
+        Self { state: Rc::new(state) }
+    }
+
+    fn build_file_filter(format: ExportFormat) -> gtk::FileFilter {
+        let filter = gtk::FileFilter::new();
+        filter.set_name(Some(format.display_name()));
+        filter.add_suffix(format.extension());
+        filter
+    }
+
+    // GtkFileDialog doesn't report which filter was picked when saving, so the
+    // format is chosen first and the file dialog only offers that one.
+    pub fn export(&self) {
+        let dialog = adw::AlertDialog::new(Some("Export Catalog"), Some("Choose a file format"));
+        dialog.add_response(CANCEL_RESPONSE, "Cancel");
+        for format in ExportFormat::all() {
+            dialog.add_response(format.extension(), format.display_name());
+        }
+        dialog.set_close_response(CANCEL_RESPONSE);
+
+        let this_weak = self.downgrade();
+        dialog.connect_response(None, move |_, response| {
+            if
+                let Some(this) = this_weak.upgrade()
+                && let Some(format) = ExportFormat::from_extension(response)
+            {
+                this.choose_export_file(format);
+            }
+        });
+        dialog.present(Some(self.state.ui.window()));
+    }
+
+    fn choose_export_file(&self, format: ExportFormat) {
+        let filter = Self::build_file_filter(format);
+        let filters = gtk::gio::ListStore::new::<gtk::FileFilter>();
+        filters.append(&filter);
+
+        let dialog = gtk::FileDialog::builder()
+            .title("Export Catalog")
+            .initial_name(format!("{name}.{extension}", name = constants::APP_NAME, extension = format.extension()))
+            .filters(&filters)
+            .default_filter(&filter)
+            .build();
+
+        let this_weak = self.downgrade();
+        dialog.save(Some(self.state.ui.window()), None::<&gtk::gio::Cancellable>, move |result| {
+            let Some(this) = this_weak.upgrade() else {
+                return;
+            };
+
+            match result {
+                Ok(file) => this.write_export(&file, format),
+                Err(error) if error.matches(gtk::DialogError::Dismissed) => {}
+                Err(error) => this.handle_err(&anyhow!("Failed to choose export file").context(error)),
+            }
+        });
+    }
+
+    fn write_export(&self, file: &gtk::gio::File, format: ExportFormat) {
+        let Some(path) = file.path() else {
+            self.handle_err(&anyhow!("Export location is not a local file"));
+            return;
+        };
+
+        let path = format.with_extension(path);
+        let content = format.serialize(self.state.repository);
+
+        match std::fs::write(&path, content) {
+            Ok(()) => self.state.ui.window().notify(&format!("Catalog exported as {format}", format = format.display_name())),
+            Err(error) => self.handle_err(&anyhow!("Failed to write {path}", path = path.display()).context(error)),
+        }
+    }
+
+    /// Products currently shown in the overview, after search and filters,
+    /// grouped by the visible categories in overview order.
+    pub fn export_visible_as_markdown(&self) -> String {
+        let repository = self.state.repository;
+        let overview_page = self.state.ui.overview_page();
+        let groups = overview_page.groups();
+
+        let sections = groups
+            .iter()
+            .filter(|(_, group)| group.is_visible())
+            .filter_map(|(category_index, group)| {
+                let category = repository.category_by_index(category_index)?;
+                let rows = group.rows();
+                let products = repository
+                    .category_products_sorted(category)?
+                    .into_iter()
+                    .filter(|(product_index, _)| rows.get(product_index).is_some_and(WidgetExt::is_visible))
+                    .map(|(_, product)| product)
+                    .collect::<Vec<_>>();
+                Some((category, products))
+            })
+            .collect::<Vec<_>>();
+
+        ExportFormat::format_markdown_list(&sections)
+    }
+
+    pub fn copy_visible_as_markdown(&self) {
+        let markdown = self.export_visible_as_markdown();
+        if markdown.is_empty() {
+            self.state.ui.window().notify("No products to copy");
+            return;
+        }
+
+        self.state.ui.window().clipboard().set_text(&markdown);
+        self.state.ui.window().notify("Product list copied as Markdown");
+    }
+
+    fn handle_err(&self, error: &anyhow::Error) {
+        self.state.ui.window().notify(&error.to_string());
+        eprintln!("Error: {error}");
+    }
+
+    pub fn downgrade(&self) -> WeakExport {
+        let state = Rc::downgrade(&self.state);
+        WeakExport { state }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_catalog::{self, CATALOG};
+
+    #[test]
+    fn csv_has_a_row_per_product() {
+        let csv = ExportFormat::Csv.serialize(test_catalog::repository());
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 1 + CATALOG.products.len());
+        assert_eq!(lines[0], "name,country,categories,summary,website");
+        assert_eq!(lines[1], "Proton Mail,Switzerland,Email Providers,End-to-end encrypted email.,https://proton.me/mail");
+        assert_eq!(lines[3], "Vivaldi,,Browsers; Email Providers,Web browser.,");
+    }
+
+    #[test]
+    fn csv_quotes_fields_with_separators() {
+        assert_eq!(ExportFormat::escape_csv("plain"), "plain");
+        assert_eq!(ExportFormat::escape_csv("a, b"), "\"a, b\"");
+        assert_eq!(ExportFormat::escape_csv("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn json_is_an_array_of_product_objects() {
+        let json = ExportFormat::Json.serialize(test_catalog::repository());
+        assert!(json.starts_with("[\n"));
+        assert!(json.ends_with("\n]\n"));
+        assert!(json.contains(
+            r#"  {"name": "Proton Mail", "country": "Switzerland", "categories": ["Email Providers"], "summary": "End-to-end encrypted email.", "websites": [{"caption": "Website", "url": "https://proton.me/mail"}, {"caption": "European Alternatives", "url": "https://european-alternatives.eu/product/proton-mail"}]},"#
+        ));
+        assert!(json.contains(
+            r#"  {"name": "Vivaldi", "country": null, "categories": ["Browsers", "Email Providers"], "summary": "Web browser.", "websites": []},"#
+        ));
+    }
+
+    #[test]
+    fn markdown_groups_products_by_category() {
+        let markdown = ExportFormat::Markdown.serialize(test_catalog::repository());
+        assert!(markdown.starts_with(&format!("# {title}\n", title = constants::APP_TITLE)));
+        assert!(markdown.contains(
+            "\n## Email Providers\n\n\
+             - [Proton Mail](https://proton.me/mail) (Switzerland) — End-to-end encrypted email.\n\
+             - **Vivaldi** — Web browser.\n"
+        ));
+    }
+
+    #[test]
+    fn format_follows_the_extension() {
+        assert_eq!(ExportFormat::from_extension("MD"), Some(ExportFormat::Markdown));
+        assert_eq!(ExportFormat::from_extension("txt"), None);
+        assert_eq!(ExportFormat::Json.with_extension(PathBuf::from("/tmp/catalog")), PathBuf::from("/tmp/catalog.json"));
+        assert_eq!(ExportFormat::Json.with_extension(PathBuf::from("/tmp/catalog.txt")), PathBuf::from("/tmp/catalog.txt"));
+    }
+
+    #[test]
+    fn markdown_list_links_products_under_category_headings() {
+        let email = &CATALOG.categories[1];
+        let cloud = &CATALOG.categories[0];
+        let sections = [
+            (email, vec![&CATALOG.products[0]]),
+            (cloud, vec![&CATALOG.products[1]]),
+        ];
+
+        assert_eq!(
+            ExportFormat::format_markdown_list(&sections),
+            "## Email Providers\n\n\
+             - [Proton Mail](https://proton.me/mail) — Switzerland\n\
+             \n\
+             ## Cloud Storage\n\n\
+             - [Nextcloud](https://nextcloud.com) — Germany\n"
+        );
+    }
+
+    #[test]
+    fn markdown_list_without_website_or_country_has_a_plain_item() {
+        let sections = [(&CATALOG.categories[2], vec![&CATALOG.products[2]])];
+        assert_eq!(ExportFormat::format_markdown_list(&sections), "## Browsers\n\n- Vivaldi\n");
+    }
+
+    #[test]
+    fn markdown_list_skips_empty_sections() {
+        let sections = [
+            (&CATALOG.categories[0], Vec::new()),
+            (&CATALOG.categories[2], vec![&CATALOG.products[2]]),
+            (&CATALOG.categories[3], Vec::new()),
+        ];
+        assert_eq!(ExportFormat::format_markdown_list(&sections), "## Browsers\n\n- Vivaldi\n");
+        assert_eq!(ExportFormat::format_markdown_list(&[]), "");
+    }
+
+    #[test]
+    fn markdown_escapes_link_brackets() {
+        assert_eq!(ExportFormat::escape_markdown("[Beta] App"), "\\[Beta\\] App");
+    }
+}
\ No newline at end of file
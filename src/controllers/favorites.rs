@@ -0,0 +1,191 @@
+use super::super::prelude::*;
+use super::super::repository::Repository;
+use super::super::settings::SettingsStore;
+use super::super::ui::Ui;
+
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+use std::rc::{Rc, Weak};
+
+const FAVORITES_KEY: &str = "favorites";
+
+type ChangedCallback = Box<dyn Fn(&str, bool) + 'static>;
+
+struct State {
+    ui: Ui,
+    repository: Repository,
+    settings: SettingsStore,
+    favorites: RefCell<BTreeSet<String>>,
+    changed_callbacks: RefCell<Vec<ChangedCallback>>
+}
+
+pub struct WeakFavorites {
+    state: Weak<State>
+}
+
+impl WeakFavorites {
+    pub fn upgrade(&self) -> Option<Favorites> {
+        self.state.upgrade().map(|state| Favorites { state })
+    }
+}
+
+#[derive(Clone)]
+pub struct Favorites {
+    state: Rc<State>
+}
+
+impl Favorites {
+
+    pub fn new(ui: Ui, repository: Repository, settings: SettingsStore) -> Self {
+        let favorites = Self::load_favorites(&settings);
+        let state = State {
+            ui,
+            repository,
+            settings,
+            favorites: RefCell::new(favorites),
+            changed_callbacks: RefCell::new(Vec::new())
+        };
+        let this = Self { state: Rc::new(state) };
+        this.setup_product_page_sync();
+        this.setup_favorite_toggled();
+        this
+    }
+
+    fn setup_product_page_sync(&self) {
+        let this_weak = self.downgrade();
+        self.state.ui.product_page().connect_index_notify(move |product_page| {
+            if
+                let Some(this) = this_weak.upgrade()
+                && let Some(product) = this.state.repository.product_by_index(product_page.index() as usize)
+            {
+                product_page.set_favorite(this.is_favorite(product.name));
+            }
+        });
+    }
+
+    fn setup_favorite_toggled(&self) {
+        let this_weak = self.downgrade();
+        self.state.ui.product_page().connect_favorite_toggled(move |product_page, favorite| {
+            if
+                let Some(this) = this_weak.upgrade()
+                && let Some(product) = this.state.repository.product_by_index(product_page.index() as usize)
+                && this.is_favorite(product.name) != favorite
+            {
+                this.toggle_favorite(product.name);
+            }
+        });
+    }
+
+    pub fn is_favorite(&self, name: &str) -> bool {
+        self.state.favorites.borrow().contains(name)
+    }
+
+    /// Sorted names of the favorite products, independent of the catalog so
+    /// entries that no longer exist are kept until the user removes them.
+    pub fn favorites(&self) -> Vec<String> {
+        self.state.favorites.borrow().iter().cloned().collect()
+    }
+
+    /// Favorite products in name order, skipping names that are no longer in
+    /// the catalog.
+    pub fn favorite_products(&self) -> Vec<usize> {
+        self.favorites()
+            .iter()
+            .filter_map(|name| self.state.repository.product_index_by_name_ci(name))
+            .collect()
+    }
+
+    pub fn toggle_favorite(&self, name: &str) -> bool {
+        let favorite = Self::toggle_name(&mut self.state.favorites.borrow_mut(), name);
+
+        if let Err(error) = self.save_favorites() {
+            self.state.ui.window().notify(&error.to_string());
+            eprintln!("Error: {error}");
+        }
+
+        for callback in self.state.changed_callbacks.borrow().iter() {
+            callback(name, favorite);
+        }
+
+        favorite
+    }
+
+    /// Adds `name` when missing and removes it otherwise, returning whether it
+    /// is now a favorite.
+    fn toggle_name(favorites: &mut BTreeSet<String>, name: &str) -> bool {
+        if favorites.remove(name) {
+            false
+        } else {
+            favorites.insert(name.to_string());
+            true
+        }
+    }
+
+    fn load_favorites(settings: &SettingsStore) -> BTreeSet<String> {
+        settings.strv(FAVORITES_KEY).into_iter().collect()
+    }
+
+    fn save_favorites(&self) -> Result<()> {
+        Self::store_favorites(&self.state.settings, &self.state.favorites.borrow())
+    }
+
+    fn store_favorites(settings: &SettingsStore, favorites: &BTreeSet<String>) -> Result<()> {
+        let names = favorites.iter().map(String::as_str).collect::<Vec<_>>();
+        settings.set_strv(FAVORITES_KEY, &names)
+    }
+
+    pub fn connect_changed<F>(&self, callback: F)
+    where
+        F: Fn(&str, bool) + 'static
+    {
+        self.state.changed_callbacks.borrow_mut().push(Box::new(callback));
+    }
+
+    pub fn downgrade(&self) -> WeakFavorites {
+        let state = Rc::downgrade(&self.state);
+        WeakFavorites { state }
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::path::PathBuf;
+
+    fn key_file_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("european-choice-favorites-{name}-{pid}", pid = std::process::id()))
+            .join("settings.ini")
+    }
+
+    #[test]
+    fn toggling_adds_then_removes_a_name() {
+        let mut favorites = BTreeSet::new();
+
+        assert!(Favorites::toggle_name(&mut favorites, "Nextcloud"));
+        assert!(favorites.contains("Nextcloud"));
+        assert!(!Favorites::toggle_name(&mut favorites, "Nextcloud"));
+        assert!(favorites.is_empty());
+    }
+
+    #[test]
+    fn favorites_persist_in_the_key_file() {
+        let path = key_file_path("persist");
+        let _ = std::fs::remove_file(&path);
+        let settings = SettingsStore::with_key_file(path.clone());
+
+        let mut favorites = Favorites::load_favorites(&settings);
+        Favorites::toggle_name(&mut favorites, "Vivaldi");
+        Favorites::toggle_name(&mut favorites, "Nextcloud");
+        Favorites::toggle_name(&mut favorites, "Vivaldi");
+        Favorites::store_favorites(&settings, &favorites).unwrap();
+
+        let reopened = SettingsStore::with_key_file(path.clone());
+        assert_eq!(Favorites::load_favorites(&reopened), BTreeSet::from([String::from("Nextcloud")]));
+        assert_eq!(reopened.strv(FAVORITES_KEY), ["Nextcloud"]);
+
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+}
@@ -0,0 +1,193 @@
+use super::super::prelude::*;
+use super::super::controllers::{FavoritesController, ProductActivationController, RecentsController};
+use super::super::models::Country;
+use super::super::repository::Repository;
+use super::super::search_engine::{GlobalResults, SearchEngine};
+use super::super::ui::Ui;
+use super::super::widgets::{GlobalSearchPopoverWidget, GlobalSearchResultKind};
+
+use std::rc::{Rc, Weak};
+
+const SECTION_RESULT_LIMIT: usize = 5;
+
+struct State {
+    ui: Ui,
+    repository: Repository,
+    engine: SearchEngine,
+    product_activation: ProductActivationController,
+    favorites: FavoritesController,
+    recents: RecentsController
+}
+
+pub struct WeakGlobalSearch {
+    state: Weak<State>
+}
+
+impl WeakGlobalSearch {
+    pub fn upgrade(&self) -> Option<GlobalSearch> {
+        self.state.upgrade().map(|state| GlobalSearch { state })
+    }
+}
+
+#[derive(Clone)]
+pub struct GlobalSearch {
+    state: Rc<State>
+}
+
+impl GlobalSearch {
+
+    pub fn new(
+        ui: Ui,
+        repository: Repository,
+        engine: SearchEngine,
+        product_activation: ProductActivationController,
+        favorites: FavoritesController,
+        recents: RecentsController
+    ) -> Self {
+        let state = State { ui, repository, engine, product_activation, favorites, recents };
+        let this = Self { state: Rc::new(state) };
+        this.setup_search_changed();
+        this.setup_result_activated();
+        this.setup_shortcuts_changed();
+        this
+    }
+
+    fn popover(&self) -> &GlobalSearchPopoverWidget {
+        self.state.ui.search_row().global_search_popover()
+    }
+
+    pub fn activate(&self) {
+        if self.popover().search_text().trim().is_empty() {
+            self.present_shortcuts();
+        }
+        self.popover().popup_search();
+    }
+
+    fn setup_search_changed(&self) {
+        let this_weak = self.downgrade();
+        self.popover().connect_search_changed(move |_, query| {
+            if let Some(this) = this_weak.upgrade() {
+                this.update_results(query);
+            }
+        });
+    }
+
+    fn setup_result_activated(&self) {
+        let this_weak = self.downgrade();
+        self.popover().connect_result_activated(move |_, kind, index| {
+            if let Some(this) = this_weak.upgrade() {
+                this.handle_result_activated(kind, index);
+            }
+        });
+    }
+
+    fn setup_shortcuts_changed(&self) {
+        let this_weak = self.downgrade();
+        self.state.favorites.connect_changed(move |_, _| {
+            if let Some(this) = this_weak.upgrade() {
+                this.refresh_shortcuts();
+            }
+        });
+
+        let this_weak = self.downgrade();
+        self.state.recents.connect_changed(move |_| {
+            if let Some(this) = this_weak.upgrade() {
+                this.refresh_shortcuts();
+            }
+        });
+    }
+
+    fn refresh_shortcuts(&self) {
+        if self.popover().search_text().trim().is_empty() {
+            self.present_shortcuts();
+        }
+    }
+
+    fn update_results(&self, query: &str) {
+        if query.trim().is_empty() {
+            self.present_shortcuts();
+            return;
+        }
+
+        let results = self.state.engine.global_search(query);
+        self.present_results(&results);
+    }
+
+    /// Shown instead of results while the query is empty.
+    fn present_shortcuts(&self) {
+        self.popover().clear_results();
+        self.append_product_section(GlobalSearchResultKind::Recent, &self.state.recents.recent_products());
+        self.append_product_section(GlobalSearchResultKind::Favorite, &self.state.favorites.favorite_products());
+    }
+
+    fn append_product_section(&self, kind: GlobalSearchResultKind, product_indices: &[usize]) {
+        if product_indices.is_empty() {
+            return;
+        }
+
+        let popover = self.popover();
+        popover.append_section(kind);
+        for &product_index in product_indices.iter().take(SECTION_RESULT_LIMIT) {
+            if let Some(product) = self.state.repository.product_by_index(product_index) {
+                popover.append_result(kind, product_index, product.name, Some(product.summary));
+            }
+        }
+    }
+
+    fn present_results(&self, results: &GlobalResults) {
+        let popover = self.popover();
+        let repository = self.state.repository;
+        popover.clear_results();
+
+        self.append_product_section(GlobalSearchResultKind::Product, &results.products);
+
+        if !results.categories.is_empty() {
+            popover.append_section(GlobalSearchResultKind::Category);
+            for &category_index in results.categories.iter().take(SECTION_RESULT_LIMIT) {
+                if let Some(category) = repository.category_by_index(category_index) {
+                    popover.append_result(GlobalSearchResultKind::Category, category_index, category.name, None);
+                }
+            }
+        }
+
+        let country_row = self.state.ui.country_row();
+        let countries = results
+            .countries
+            .iter()
+            .copied()
+            .filter(|&country| country_row.has_country(country))
+            .take(SECTION_RESULT_LIMIT)
+            .collect::<Vec<_>>();
+
+        if !countries.is_empty() {
+            popover.append_section(GlobalSearchResultKind::Country);
+            for country in countries {
+                popover.append_result(GlobalSearchResultKind::Country, country as usize, country.display_name(), None);
+            }
+        }
+    }
+
+    fn handle_result_activated(&self, kind: GlobalSearchResultKind, index: usize) {
+        match kind {
+            GlobalSearchResultKind::Product
+            | GlobalSearchResultKind::Recent
+            | GlobalSearchResultKind::Favorite => {
+                self.state.product_activation.open_product(index);
+            },
+            GlobalSearchResultKind::Category => {
+                self.state.ui.category_list().select_row_by_index(index);
+                self.state.ui.overview_page().scroll_to_group_index(index);
+            },
+            GlobalSearchResultKind::Country => {
+                if let Some(country) = Country::from_index(index) {
+                    self.state.ui.country_row().select_country(country);
+                }
+            },
+        }
+    }
+
+    pub fn downgrade(&self) -> WeakGlobalSearch {
+        let state = Rc::downgrade(&self.state);
+        WeakGlobalSearch { state }
+    }
+}
\ No newline at end of file
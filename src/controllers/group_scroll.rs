@@ -1,6 +1,6 @@
 use super::super::prelude::*;
 use gtk::graphene::Point;
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 use std::rc::{Rc, Weak};
 use std::time::Duration;
 
@@ -10,14 +10,43 @@ const SCROLL_DEBOUNCE: Duration = Duration::from_millis(250);
 const ANIMATION_DURATION: Duration = Duration::from_millis(300);
 const ANIMATION_FRAME_INTERVAL: Duration = Duration::from_millis(16);
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollEasing {
+    Linear,
+    #[default]
+    EaseOutCubic,
+    EaseInOutCubic,
+}
+
+impl ScrollEasing {
+    #[must_use]
+    pub fn apply(self, progress: f64) -> f64 {
+        match self {
+            Self::Linear => progress,
+            Self::EaseOutCubic => 1.0 - (1.0 - progress).powi(3),
+            Self::EaseInOutCubic => {
+                if progress < 0.5 {
+                    4.0 * progress.powi(3)
+                } else {
+                    1.0 - (-2.0f64).mul_add(progress, 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
 struct State {
     overview_page: OverviewPageWidget,
     container_box: gtk::Box,
     scrolled_window: gtk::ScrolledWindow,
     previous_scroll_position: Cell<f64>,
     debounce_timeout: Cell<Option<(f64, glib::SourceId)>>,
-    animation_timeout: Cell<Option<glib::SourceId>>,
+    animation_timeout: Cell<Option<(f64, Option<usize>, glib::SourceId)>>,
+    animation_duration: Cell<Duration>,
+    easing: Cell<ScrollEasing>,
+    reduced_motion: Cell<bool>,
     on_active_changed: Box<dyn Fn(usize) + 'static>,
+    on_progress: RefCell<Option<Box<dyn Fn(f64) + 'static>>>,
 }
 
 struct WeakGroupScroll {
@@ -51,15 +80,101 @@ impl GroupScroll {
             previous_scroll_position: Cell::new(0.0),
             debounce_timeout: Cell::new(None),
             animation_timeout: Cell::new(None),
+            animation_duration: Cell::new(ANIMATION_DURATION),
+            easing: Cell::new(ScrollEasing::default()),
+            reduced_motion: Cell::new(!Self::animations_enabled()),
             on_active_changed: Box::new(on_active_changed),
+            on_progress: RefCell::new(None),
         });
 
         let this = Self { state };
         this.setup_scroll_change_handler();
         this.setup_scroll_key_handler();
+        this.setup_group_navigation_keys();
+        this.setup_animation_settings_handler();
         this
     }
 
+    fn animations_enabled() -> bool {
+        gtk::Settings::default().is_none_or(|settings| settings.is_gtk_enable_animations())
+    }
+
+    #[must_use]
+    pub fn with_animation_duration(self, duration: Duration) -> Self {
+        self.set_animation_duration(duration);
+        self
+    }
+
+    #[must_use]
+    pub fn with_easing(self, easing: ScrollEasing) -> Self {
+        self.set_easing(easing);
+        self
+    }
+
+    /// A zero duration jumps straight to the target, like reduced motion.
+    pub fn set_animation_duration(&self, duration: Duration) {
+        self.state.animation_duration.set(duration);
+    }
+
+    pub fn set_easing(&self, easing: ScrollEasing) {
+        self.state.easing.set(easing);
+    }
+
+    #[must_use]
+    pub fn with_on_progress<F>(self, on_progress: F) -> Self
+    where
+        F: Fn(f64) + 'static,
+    {
+        self.set_on_progress(on_progress);
+        self
+    }
+
+    /// Called on every frame of a programmatic scroll with the eased progress,
+    /// from 0.0 up to 1.0 on the last frame. Instant jumps report 1.0 only.
+    pub fn set_on_progress<F>(&self, on_progress: F)
+    where
+        F: Fn(f64) + 'static,
+    {
+        self.state.on_progress.replace(Some(Box::new(on_progress)));
+    }
+
+    fn report_progress(&self, progress: f64) {
+        if let Some(on_progress) = self.state.on_progress.borrow().as_ref() {
+            on_progress(progress);
+        }
+    }
+
+    /// Switching modes cancels a running animation and jumps straight to its target.
+    pub fn set_reduced_motion(&self, reduced_motion: bool) {
+        if self.state.reduced_motion.replace(reduced_motion) != reduced_motion
+            && let Some((target_position, active_index, timeout_id)) = self.state.animation_timeout.take()
+        {
+            timeout_id.remove();
+            self.state.scrolled_window.vadjustment().set_value(target_position);
+            self.report_progress(1.0);
+            if let Some(index) = active_index {
+                (self.state.on_active_changed)(index);
+            }
+        }
+    }
+
+    pub fn is_reduced_motion(&self) -> bool {
+        self.state.reduced_motion.get()
+    }
+
+    fn setup_animation_settings_handler(&self) {
+        let Some(settings) = gtk::Settings::default() else {
+            return;
+        };
+
+        let this_weak = self.downgrade();
+        settings.connect_gtk_enable_animations_notify(move |settings| {
+            if let Some(this) = this_weak.upgrade() {
+                this.set_reduced_motion(!settings.is_gtk_enable_animations());
+            }
+        });
+    }
+
     fn setup_scroll_change_handler(&self) {
         let this_weak = self.downgrade();
         self.state.scrolled_window.vadjustment().connect_value_changed(move |_| {
@@ -78,6 +193,33 @@ impl GroupScroll {
         });
     }
 
+    fn setup_group_navigation_keys(&self) {
+        let key_controller = gtk::EventControllerKey::new();
+        let this_weak = self.downgrade();
+        key_controller.connect_key_pressed(move |_, key, _, modifiers| {
+            let Some(this) = this_weak.upgrade() else {
+                return glib::Propagation::Proceed;
+            };
+
+            if !modifiers.contains(gtk::gdk::ModifierType::CONTROL_MASK) {
+                return glib::Propagation::Proceed;
+            }
+
+            match key {
+                gtk::gdk::Key::Down => {
+                    this.scroll_to_next_group();
+                    glib::Propagation::Stop
+                }
+                gtk::gdk::Key::Up => {
+                    this.scroll_to_previous_group();
+                    glib::Propagation::Stop
+                }
+                _ => glib::Propagation::Proceed,
+            }
+        });
+        self.state.scrolled_window.add_controller(key_controller);
+    }
+
     fn schedule_debounced_scroll_handler(&self) {
         if let Some((_, id)) = self.state.debounce_timeout.take() {
             id.remove();
@@ -105,10 +247,23 @@ impl GroupScroll {
                 self.scroll_to_bottom();
                 true
             }
+            (false, scroll_type) => Self::page_direction(scroll_type)
+                .and_then(|forward| self.find_adjacent_visible_group_index(forward))
+                .is_some_and(|index| self.scroll_to(index)),
             _ => false,
         }
     }
 
+    /// Maps paging scroll types to a group direction: `Some(true)` for the
+    /// next group, `Some(false)` for the previous one.
+    const fn page_direction(scroll_type: gtk::ScrollType) -> Option<bool> {
+        match scroll_type {
+            gtk::ScrollType::PageDown | gtk::ScrollType::PageForward => Some(true),
+            gtk::ScrollType::PageUp | gtk::ScrollType::PageBackward => Some(false),
+            _ => None,
+        }
+    }
+
     pub fn scroll_to(&self, index: usize) -> bool {
         if 
             !self.is_current_active(index)
@@ -132,14 +287,57 @@ impl GroupScroll {
             .is_some_and(|index| self.scroll_to(index))
     }
 
-    pub fn scroll_to_bottom(&self) -> bool {
+    fn scroll_to_bottom(&self) -> bool {
         self
             .find_last_visible_group_index()
             .is_some_and(|index| self.scroll_to(index))
     }
 
+    fn scroll_to_next_group(&self) -> bool {
+        self.find_adjacent_visible_group_index(true)
+            .is_some_and(|index| self.scroll_to(index))
+    }
+
+    fn scroll_to_previous_group(&self) -> bool {
+        self.find_adjacent_visible_group_index(false)
+            .is_some_and(|index| self.scroll_to(index))
+    }
+
+    /// Group next to `current` in `visible_indices`, or `None` at either end.
+    /// Without an active group the first or last visible group is returned.
+    fn adjacent_group_index(visible_indices: &[usize], current: Option<usize>, forward: bool) -> Option<usize> {
+        let Some(position) = current.and_then(|current| visible_indices.iter().position(|&index| index == current)) else {
+            return if forward { visible_indices.first() } else { visible_indices.last() }.copied();
+        };
+
+        if forward {
+            visible_indices.get(position + 1).copied()
+        } else {
+            position.checked_sub(1).and_then(|previous| visible_indices.get(previous)).copied()
+        }
+    }
+
+    /// Whether a programmatic scroll animation is running.
+    pub fn is_animating(&self) -> bool {
+        let timeout_id = self.state.animation_timeout.take();
+        let is_animating = timeout_id.is_some();
+        self.state.animation_timeout.set(timeout_id);
+        is_animating
+    }
+
+    pub fn scroll_fraction(&self) -> f64 {
+        let adjustment = self.state.scrolled_window.vadjustment();
+        let scrollable_height = adjustment.upper() - adjustment.page_size();
+
+        if scrollable_height <= 0.0 {
+            0.0
+        } else {
+            (adjustment.value() / scrollable_height).clamp(0.0, 1.0)
+        }
+    }
+
     fn animate_scroll_to_position(&self, target_position: f64, active_index: Option<usize>) {
-        if let Some(timeout_id) = self.state.animation_timeout.take() {
+        if let Some((_, _, timeout_id)) = self.state.animation_timeout.take() {
             timeout_id.remove();
         }
 
@@ -154,6 +352,15 @@ impl GroupScroll {
             return;
         }
 
+        if self.is_reduced_motion() || self.state.animation_duration.get().is_zero() {
+            adjustment.set_value(target_position);
+            self.report_progress(1.0);
+            if let Some(index) = active_index {
+                (self.state.on_active_changed)(index);
+            }
+            return;
+        }
+
         let animation_start_time = std::time::Instant::now();
         let this_weak = self.downgrade();
 
@@ -164,7 +371,7 @@ impl GroupScroll {
         };
 
         let timeout_id = glib::timeout_add_local(ANIMATION_FRAME_INTERVAL, animation_callback);
-        self.state.animation_timeout.set(Some(timeout_id));
+        self.state.animation_timeout.set(Some((target_position, active_index, timeout_id)));
     }
 
     fn execute_animation_frame(
@@ -174,13 +381,16 @@ impl GroupScroll {
         distance: f64,
         active_index: Option<usize>
     ) -> glib::ControlFlow {
-        let elapsed = start_time.elapsed();
-        let progress = (elapsed.as_millis() as f64 / ANIMATION_DURATION.as_millis() as f64).min(1.0);
-        let eased_progress = 1.0 - (1.0 - progress).powi(3);
+        let (eased_progress, finished) = Self::animation_progress(
+            start_time.elapsed(),
+            self.state.animation_duration.get(),
+            self.state.easing.get(),
+        );
         let current_position = distance.mul_add(eased_progress, start_position);
         self.state.scrolled_window.vadjustment().set_value(current_position);
+        self.report_progress(eased_progress);
 
-        if progress >= 1.0 {
+        if finished {
             self.state.animation_timeout.set(None);
             if let Some(index) = active_index {
                 (self.state.on_active_changed)(index);
@@ -191,6 +401,35 @@ impl GroupScroll {
         }
     }
 
+    /// Eased progress `elapsed` into an animation and whether it has finished.
+    /// The last frame reports exactly 1.0, whatever the easing.
+    fn animation_progress(elapsed: Duration, duration: Duration, easing: ScrollEasing) -> (f64, bool) {
+        let progress = (elapsed.as_secs_f64() / duration.as_secs_f64()).min(1.0);
+        if progress >= 1.0 {
+            (1.0, true)
+        } else {
+            (easing.apply(progress), false)
+        }
+    }
+
+    fn visible_group_indices(&self) -> Vec<usize> {
+        self.state
+            .overview_page
+            .groups()
+            .iter()
+            .filter(|(_, group)| group.is_visible())
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    fn find_adjacent_visible_group_index(&self, forward: bool) -> Option<usize> {
+        let current = self.state
+            .overview_page
+            .get_active_group()
+            .map(|group| group.index() as usize);
+        Self::adjacent_group_index(&self.visible_group_indices(), current, forward)
+    }
+
     fn find_first_visible_group_index(&self) -> Option<usize> {
         for (index, group) in self.state.overview_page.groups().iter() {
             if group.is_visible() {
@@ -308,4 +547,97 @@ impl GroupScroll {
         let state = Rc::downgrade(&self.state);
         WeakGroupScroll { state }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EASINGS: [ScrollEasing; 3] = [
+        ScrollEasing::Linear,
+        ScrollEasing::EaseOutCubic,
+        ScrollEasing::EaseInOutCubic,
+    ];
+
+    #[test]
+    fn easings_start_at_zero_and_end_at_one() {
+        for easing in EASINGS {
+            assert!(easing.apply(0.0).abs() < f64::EPSILON, "{easing:?}");
+            assert!((easing.apply(1.0) - 1.0).abs() < f64::EPSILON, "{easing:?}");
+        }
+    }
+
+    #[test]
+    fn easings_never_move_backwards() {
+        for easing in EASINGS {
+            let samples = (0..=100).map(|step| easing.apply(f64::from(step) / 100.0)).collect::<Vec<_>>();
+            assert!(samples.windows(2).all(|pair| pair[0] <= pair[1]), "{easing:?}");
+        }
+    }
+
+    #[test]
+    fn ease_out_is_ahead_of_linear_halfway() {
+        assert!(ScrollEasing::EaseOutCubic.apply(0.5) > ScrollEasing::Linear.apply(0.5));
+        assert!((ScrollEasing::EaseInOutCubic.apply(0.5) - 0.5).abs() < f64::EPSILON);
+        assert_eq!(ScrollEasing::default(), ScrollEasing::EaseOutCubic);
+    }
+
+    #[test]
+    fn animation_progress_rises_to_exactly_one() {
+        for easing in EASINGS {
+            let frames = (0..=25)
+                .map(|frame| GroupScroll::animation_progress(ANIMATION_FRAME_INTERVAL * frame, ANIMATION_DURATION, easing))
+                .collect::<Vec<_>>();
+            let finished_at = frames.iter().position(|&(_, finished)| finished).unwrap();
+
+            assert!(frames.windows(2).all(|pair| pair[0].0 <= pair[1].0), "{easing:?}");
+            assert!(frames[..finished_at].iter().all(|&(progress, _)| progress < 1.0), "{easing:?}");
+            assert!(frames[finished_at..].iter().all(|&(progress, finished)| finished && progress.to_bits() == 1.0f64.to_bits()));
+        }
+    }
+
+    #[test]
+    fn animation_progress_follows_the_duration() {
+        let duration = Duration::from_millis(100);
+
+        assert_eq!(GroupScroll::animation_progress(Duration::ZERO, duration, ScrollEasing::Linear), (0.0, false));
+        assert_eq!(GroupScroll::animation_progress(Duration::from_millis(50), duration, ScrollEasing::Linear), (0.5, false));
+        assert_eq!(GroupScroll::animation_progress(Duration::from_millis(100), duration, ScrollEasing::Linear), (1.0, true));
+    }
+
+    #[test]
+    fn paging_keys_map_to_a_direction() {
+        assert_eq!(GroupScroll::page_direction(gtk::ScrollType::PageDown), Some(true));
+        assert_eq!(GroupScroll::page_direction(gtk::ScrollType::PageForward), Some(true));
+        assert_eq!(GroupScroll::page_direction(gtk::ScrollType::PageUp), Some(false));
+        assert_eq!(GroupScroll::page_direction(gtk::ScrollType::PageBackward), Some(false));
+    }
+
+    #[test]
+    fn horizontal_keys_do_not_page() {
+        assert_eq!(GroupScroll::page_direction(gtk::ScrollType::PageLeft), None);
+        assert_eq!(GroupScroll::page_direction(gtk::ScrollType::PageRight), None);
+        assert_eq!(GroupScroll::page_direction(gtk::ScrollType::StepLeft), None);
+        assert_eq!(GroupScroll::page_direction(gtk::ScrollType::StepRight), None);
+    }
+
+    #[test]
+    fn adjacent_groups_stop_at_either_end() {
+        let visible = [0, 2, 5];
+
+        assert_eq!(GroupScroll::adjacent_group_index(&visible, Some(0), false), None);
+        assert_eq!(GroupScroll::adjacent_group_index(&visible, Some(5), true), None);
+        assert_eq!(GroupScroll::adjacent_group_index(&visible, Some(0), true), Some(2));
+        assert_eq!(GroupScroll::adjacent_group_index(&visible, Some(5), false), Some(2));
+    }
+
+    #[test]
+    fn adjacent_groups_without_an_active_group_start_at_either_end() {
+        let visible = [0, 2, 5];
+
+        assert_eq!(GroupScroll::adjacent_group_index(&visible, None, true), Some(0));
+        assert_eq!(GroupScroll::adjacent_group_index(&visible, None, false), Some(5));
+        assert_eq!(GroupScroll::adjacent_group_index(&visible, Some(3), true), Some(0));
+        assert_eq!(GroupScroll::adjacent_group_index(&[], None, true), None);
+    }
+}
@@ -0,0 +1,86 @@
+use super::super::prelude::*;
+use super::super::repository::Repository;
+use super::super::settings::SettingsStore;
+use super::super::ui::Ui;
+
+use std::rc::{Rc, Weak};
+
+const LAST_CATEGORY_KEY: &str = "last-category";
+
+struct State {
+    ui: Ui,
+    repository: Repository,
+    settings: SettingsStore
+}
+
+pub struct WeakLastCategory {
+    state: Weak<State>
+}
+
+impl WeakLastCategory {
+    pub fn upgrade(&self) -> Option<LastCategory> {
+        self.state.upgrade().map(|state| LastCategory { state })
+    }
+}
+
+pub struct LastCategory {
+    state: Rc<State>
+}
+
+impl LastCategory {
+
+    pub fn new(ui: Ui, repository: Repository, settings: SettingsStore) -> Self {
+        let state = Rc::new(State { ui, repository, settings });
+        let controller = Self { state };
+        controller.setup_active_group_changed();
+        controller.restore_last_category();
+        controller
+    }
+
+    fn setup_active_group_changed(&self) {
+        let controller_weak = self.downgrade();
+        self.state.ui.overview_page().connect_active_group_changed(move |_, group| {
+            if let Some(controller) = controller_weak.upgrade()
+                && let Err(error) = controller.save_last_category(group.index() as usize)
+            {
+                controller.state.ui.window().notify(&error.to_string());
+            }
+        });
+    }
+
+    fn save_last_category(&self, category_index: usize) -> Result<()> {
+        if let Some(category) = self.state.repository.category_by_index(category_index) {
+            self.state.settings.set_string(LAST_CATEGORY_KEY, category.slug)?;
+        }
+        Ok(())
+    }
+
+    fn load_last_category_index(&self) -> Option<usize> {
+        let slug = self.state.settings.string(LAST_CATEGORY_KEY, "");
+        if slug.is_empty() {
+            return None;
+        }
+
+        self.state.repository.category_index_by_slug(&slug)
+    }
+
+    fn restore_last_category(&self) {
+        let Some(category_index) = self.load_last_category_index() else {
+            return;
+        };
+
+        // Group positions are only known once the overview has been laid out.
+        let controller_weak = self.downgrade();
+        glib::idle_add_local_once(move || {
+            if let Some(controller) = controller_weak.upgrade() {
+                controller.state.ui.overview_page().scroll_to_group_index(category_index);
+            }
+        });
+    }
+
+    pub fn downgrade(&self) -> WeakLastCategory {
+        let state = Rc::downgrade(&self.state);
+        WeakLastCategory { state }
+    }
+
+}
\ No newline at end of file
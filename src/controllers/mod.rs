@@ -4,10 +4,28 @@ mod product_activation;
 mod product_row_activation;
 mod window_size;
 mod actions;
+mod window_title;
+mod export;
+mod global_search;
+mod last_category;
+mod favorites;
+mod preferences;
+mod comparison;
+mod recents;
 
 pub use self::group_scroll::GroupScroll as GroupScrollController;
+pub use self::group_scroll::ScrollEasing;
 pub use self::search::Search as SearchController;
 pub use self::product_activation::ProductActivation as ProductActivationController;
 pub use self::product_row_activation::ProductRowActivation as ProductRowActivationController;
 pub use self::window_size::WindowSize as WindowSizeController;
-pub use self::actions::Actions as ActionsController;
\ No newline at end of file
+pub use self::actions::Actions as ActionsController;
+pub use self::window_title::WindowTitle as WindowTitleController;
+pub use self::export::Export as ExportController;
+pub use self::global_search::GlobalSearch as GlobalSearchController;
+pub use self::last_category::LastCategory as LastCategoryController;
+pub use self::favorites::Favorites as FavoritesController;
+pub use self::preferences::Preferences as PreferencesController;
+pub use self::preferences::ColorScheme;
+pub use self::comparison::Comparison as ComparisonController;
+pub use self::recents::Recents as RecentsController;
\ No newline at end of file
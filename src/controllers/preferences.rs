@@ -0,0 +1,272 @@
+use super::super::prelude::*;
+use super::super::constants;
+use super::super::controllers::SearchController;
+use super::super::settings::SettingsStore;
+use super::super::ui::Ui;
+
+use std::rc::{Rc, Weak};
+
+const COLOR_SCHEME_KEY: &str = "color-scheme";
+pub const SHOW_SOURCE_WEBSITE_KEY: &str = "show-source-website";
+pub const SEARCH_MATCH_ANY_KEY: &str = "search-match-any";
+pub const MULTI_COUNTRY_FILTER_KEY: &str = "multi-country-filter";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ColorScheme {
+    System,
+    Light,
+    #[default]
+    Dark,
+}
+
+impl ColorScheme {
+    pub const fn all() -> &'static [Self] {
+        &[Self::System, Self::Light, Self::Dark]
+    }
+
+    pub const fn display_name(self) -> &'static str {
+        match self {
+            Self::System => "System",
+            Self::Light => "Light",
+            Self::Dark => "Dark",
+        }
+    }
+
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::System => "system",
+            Self::Light => "light",
+            Self::Dark => "dark",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::all().iter().copied().find(|scheme| scheme.key() == key)
+    }
+
+    pub fn position(self) -> u32 {
+        Self::all()
+            .iter()
+            .position(|&scheme| scheme == self)
+            .and_then(|position| u32::try_from(position).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn from_position(position: u32) -> Option<Self> {
+        usize::try_from(position).ok().and_then(|position| Self::all().get(position).copied())
+    }
+
+    pub const fn to_adw(self) -> adw::ColorScheme {
+        match self {
+            Self::System => adw::ColorScheme::Default,
+            Self::Light => adw::ColorScheme::ForceLight,
+            Self::Dark => adw::ColorScheme::ForceDark,
+        }
+    }
+
+    pub fn load(settings: &SettingsStore) -> Self {
+        Self::from_key(&settings.string(COLOR_SCHEME_KEY, Self::default().key())).unwrap_or_default()
+    }
+
+    pub fn save(self, settings: &SettingsStore) -> Result<()> {
+        settings.set_string(COLOR_SCHEME_KEY, self.key())
+    }
+
+    pub fn apply(self) {
+        adw::StyleManager::default().set_color_scheme(self.to_adw());
+    }
+}
+
+struct State {
+    ui: Ui,
+    settings: SettingsStore,
+    search_controller: SearchController
+}
+
+pub struct WeakPreferences {
+    state: Weak<State>
+}
+
+impl WeakPreferences {
+    pub fn upgrade(&self) -> Option<Preferences> {
+        self.state.upgrade().map(|state| Preferences { state })
+    }
+}
+
+#[derive(Clone)]
+pub struct Preferences {
+    state: Rc<State>
+}
+
+impl Preferences {
+
+    pub fn new(ui: Ui, settings: SettingsStore, search_controller: SearchController) -> Self {
+        let state = State { ui, settings, search_controller };
+        Self { state: Rc::new(state) }
+    }
+
+    pub fn present(&self) {
+        let group = adw::PreferencesGroup::builder()
+            .title("Appearance")
+            .build();
+        group.add(&self.build_color_scheme_row());
+
+        let product_page_group = adw::PreferencesGroup::builder()
+            .title("Product Page")
+            .build();
+        product_page_group.add(&self.build_show_source_website_row());
+
+        let search_group = adw::PreferencesGroup::builder()
+            .title("Search")
+            .build();
+        search_group.add(&self.build_search_match_any_row());
+        search_group.add(&self.build_multi_country_filter_row());
+
+        let page = adw::PreferencesPage::new();
+        page.add(&group);
+        page.add(&search_group);
+        page.add(&product_page_group);
+
+        let dialog = adw::PreferencesDialog::new();
+        dialog.add(&page);
+        dialog.present(Some(self.state.ui.window()));
+    }
+
+    fn build_color_scheme_row(&self) -> adw::ComboRow {
+        let names = ColorScheme::all()
+            .iter()
+            .map(|scheme| scheme.display_name())
+            .collect::<Vec<_>>();
+        let row = adw::ComboRow::builder()
+            .title("Color Scheme")
+            .model(&gtk::StringList::new(&names))
+            .selected(ColorScheme::load(&self.state.settings).position())
+            .build();
+
+        let this_weak = self.downgrade();
+        row.connect_selected_notify(move |row| {
+            if
+                let Some(this) = this_weak.upgrade()
+                && let Some(scheme) = ColorScheme::from_position(row.selected())
+            {
+                this.set_color_scheme(scheme);
+            }
+        });
+        row
+    }
+
+    fn build_show_source_website_row(&self) -> adw::SwitchRow {
+        let row = adw::SwitchRow::builder()
+            .title("Show Source Website")
+            .subtitle(format!("List the {source} page with the product websites", source = constants::APP_SOURCE_NAME))
+            .active(self.state.settings.boolean(SHOW_SOURCE_WEBSITE_KEY, true))
+            .build();
+
+        let this_weak = self.downgrade();
+        row.connect_active_notify(move |row| {
+            if let Some(this) = this_weak.upgrade() {
+                if let Err(error) = this.state.settings.set_boolean(SHOW_SOURCE_WEBSITE_KEY, row.is_active()) {
+                    this.handle_err(&error);
+                }
+            }
+        });
+        row
+    }
+
+    fn build_search_match_any_row(&self) -> adw::SwitchRow {
+        let row = adw::SwitchRow::builder()
+            .title("Match Any Word")
+            .subtitle("Show products that match at least one of the search words")
+            .active(self.state.settings.boolean(SEARCH_MATCH_ANY_KEY, false))
+            .build();
+
+        let this_weak = self.downgrade();
+        row.connect_active_notify(move |row| {
+            if let Some(this) = this_weak.upgrade() {
+                if let Err(error) = this.state.settings.set_boolean(SEARCH_MATCH_ANY_KEY, row.is_active()) {
+                    this.handle_err(&error);
+                }
+                this.state.search_controller.refresh();
+            }
+        });
+        row
+    }
+
+    fn build_multi_country_filter_row(&self) -> adw::SwitchRow {
+        let row = adw::SwitchRow::builder()
+            .title("Filter by Several Countries")
+            .subtitle("Let the country filter select more than one country")
+            .active(self.state.settings.boolean(MULTI_COUNTRY_FILTER_KEY, false))
+            .build();
+
+        let this_weak = self.downgrade();
+        row.connect_active_notify(move |row| {
+            if let Some(this) = this_weak.upgrade() {
+                if let Err(error) = this.state.settings.set_boolean(MULTI_COUNTRY_FILTER_KEY, row.is_active()) {
+                    this.handle_err(&error);
+                }
+                this.state.ui.country_row().set_multi_select(row.is_active());
+            }
+        });
+        row
+    }
+
+    fn set_color_scheme(&self, scheme: ColorScheme) {
+        scheme.apply();
+        if let Err(error) = scheme.save(&self.state.settings) {
+            self.handle_err(&error);
+        }
+    }
+
+    fn handle_err(&self, error: &anyhow::Error) {
+        self.state.ui.window().notify(&error.to_string());
+        eprintln!("Error: {error}");
+    }
+
+    pub fn downgrade(&self) -> WeakPreferences {
+        let state = Rc::downgrade(&self.state);
+        WeakPreferences { state }
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_round_trip() {
+        for &scheme in ColorScheme::all() {
+            assert_eq!(ColorScheme::from_key(scheme.key()), Some(scheme));
+        }
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        assert_eq!(ColorScheme::from_key(""), None);
+        assert_eq!(ColorScheme::from_key("Dark"), None);
+        assert_eq!(ColorScheme::from_key("sepia"), None);
+    }
+
+    #[test]
+    fn keys_match_the_schema_choices() {
+        let keys = ColorScheme::all().iter().map(|scheme| scheme.key()).collect::<Vec<_>>();
+        assert_eq!(keys, ["system", "light", "dark"]);
+        assert_eq!(ColorScheme::default().key(), "dark");
+    }
+
+    #[test]
+    fn dark_forces_the_dark_style() {
+        assert_eq!(ColorScheme::Dark.to_adw(), adw::ColorScheme::ForceDark);
+        assert_eq!(ColorScheme::Light.to_adw(), adw::ColorScheme::ForceLight);
+        assert_eq!(ColorScheme::System.to_adw(), adw::ColorScheme::Default);
+    }
+
+    #[test]
+    fn positions_round_trip() {
+        for &scheme in ColorScheme::all() {
+            assert_eq!(ColorScheme::from_position(scheme.position()), Some(scheme));
+        }
+        assert_eq!(ColorScheme::from_position(3), None);
+    }
+}
\ No newline at end of file
@@ -1,7 +1,10 @@
 use super::super::prelude::*;
+use super::super::constants;
+use super::super::settings::SettingsStore;
 use super::super::ui::Ui;
 use super::super::models::Product;
 use super::super::repository::Repository;
+use super::preferences::SHOW_SOURCE_WEBSITE_KEY;
 use super::super::widgets::{
     OverviewProductRowWidget,
     NavigationPage,
@@ -13,7 +16,8 @@ use std::rc::{Rc, Weak};
 
 struct State {
     ui: Ui,
-    repository: Repository
+    repository: Repository,
+    settings: SettingsStore
 }
 
 struct WeakProductActivation {
@@ -26,21 +30,22 @@ impl WeakProductActivation {
     }
 }
 
+#[derive(Clone)]
 pub struct ProductActivation {
     state: Rc<State>
 }
 
 impl ProductActivation {
 
-    pub fn new(ui: Ui, repository: Repository) -> Self {
-        let state = State { ui, repository };
+    pub fn new(ui: Ui, repository: Repository, settings: SettingsStore) -> Self {
+        let state = State { ui, repository, settings };
         let this = Self { state: Rc::new(state) };
         this.setup_rows_activation();
         this
     }
 
     fn setup_rows_activation(&self) {
-        for (_, group) in self.state.ui.overview_page().groups().iter() {
+        for group in self.state.ui.overview_page().groups().values() {
             for row in group.rows().values() {
                 let this_weak = self.downgrade();
                 row.connect_activated(move |row| {
@@ -53,18 +58,25 @@ impl ProductActivation {
     }
 
     fn navigate_to_product_page(&self, row: &OverviewProductRowWidget) {
-        let product_index = row.index() as usize;
-        if let Some(product) = self.state.repository.product_by_index(product_index) {
-            self.update_product_details(product_index, product);
-            self.state.ui.navigation().push_page(NavigationPage::Product);
-        }
+        self.open_product(row.index() as usize);
+    }
+
+    pub fn open_product(&self, product_index: usize) -> bool {
+        let Some(product) = self.state.repository.product_by_index(product_index) else {
+            return false;
+        };
+
+        self.update_product_details(product_index, product);
+        self.state.ui.navigation().push_page(NavigationPage::Product);
+        true
     }
 
     fn update_product_details(&self, product_index: usize, product: &Product) {
         let product_page = self.state.ui.product_page();
         product_page.set_name(product.name);
         product_page.set_description(product.description);
-        product_page.set_logo(product.logo);
+        product_page.set_product_summary(product.summary, product.description);
+        product_page.set_product_logo(product.logo, &product.initials());
         product_page.set_index(product_index as u32);
         product_page.remove_all_rows();
 
@@ -73,12 +85,19 @@ impl ProductActivation {
             product_page.append_row(row, ProductRowType::Country);
         }
 
-        for (index, (property, website)) in product.websites.iter().enumerate() {
+        let external_websites = product.websites
+            .iter()
+            .filter(|(caption, _)| !Self::is_source_website(caption))
+            .count();
+        product_page.set_open_all_websites_visible(external_websites > 1);
+
+        let show_source_website = self.state.settings.boolean(SHOW_SOURCE_WEBSITE_KEY, true);
+        for (index, (property, website)) in Self::shown_websites(product, show_source_website) {
             let row = ProductRowWidget::from_website(property, website, index);
             product_page.append_row(row, ProductRowType::Website);
         }
 
-        for &category_index in product.categories {
+        for category_index in self.state.repository.product_categories_by_size(product) {
             if let Some(category) = self.state.repository.category_by_index(category_index) {
                 let row = ProductRowWidget::from_category(category, category_index);
                 product_page.append_row(row, ProductRowType::Category);
@@ -86,6 +105,20 @@ impl ProductActivation {
         }
     }
 
+    pub fn is_source_website(caption: &str) -> bool {
+        caption == constants::APP_SOURCE_NAME
+    }
+
+    /// Websites listed on the product page, keeping their index in the product.
+    fn shown_websites(product: &Product, show_source_website: bool) -> Vec<(usize, (&'static str, &'static str))> {
+        product.websites
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(_, (caption, _))| show_source_website || !Self::is_source_website(caption))
+            .collect()
+    }
+
     fn downgrade(&self) -> WeakProductActivation {
         let state = Rc::downgrade(&self.state);
         WeakProductActivation { state }
@@ -93,3 +126,30 @@ impl ProductActivation {
 
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_catalog;
+
+    fn shown_urls(product_index: usize, show_source_website: bool) -> Vec<(usize, &'static str)> {
+        let product = &test_catalog::CATALOG.products[product_index];
+        ProductActivation::shown_websites(product, show_source_website)
+            .into_iter()
+            .map(|(index, (_, website))| (index, website))
+            .collect()
+    }
+
+    #[test]
+    fn source_website_is_listed_when_shown() {
+        assert_eq!(shown_urls(0, true), [
+            (0, "https://proton.me/mail"),
+            (1, "https://european-alternatives.eu/product/proton-mail"),
+        ]);
+    }
+
+    #[test]
+    fn source_website_is_skipped_when_hidden() {
+        assert_eq!(shown_urls(0, false), [(0, "https://proton.me/mail")]);
+        assert_eq!(shown_urls(1, false), [(0, "https://nextcloud.com")]);
+    }
+}
\ No newline at end of file
@@ -3,7 +3,9 @@ use super::super::ui::Ui;
 use super::super::repository::Repository;
 use super::super::widgets::{ProductRowType, ProductRowWidget, NavigationPage};
 use super::super::models::{Product, Country};
+use super::product_activation::ProductActivation;
 
+use std::cell::{Cell, RefCell};
 use std::rc::{Rc, Weak};
 use std::time::Duration;
 
@@ -34,6 +36,9 @@ impl ProductRowActivation {
         let state = State { ui, repository };
         let this = Self { state: Rc::new(state) };
         this.setup_rows_activation();
+        this.setup_rows_copy_request();
+        this.setup_open_all_websites();
+        this.setup_share_requested();
         this
     }
 
@@ -44,6 +49,7 @@ impl ProductRowActivation {
                 let Some(this) = this_weak.upgrade()
                 && let Some(product) = this.state.repository.product_by_index(product_page.index() as usize)
             {
+                this.state.ui.navigation().push_filters(this.state.ui.sidebar().filters());
                 this.state.ui.sidebar().clear_changes();
                 match row_type {
                     ProductRowType::Website => this.handle_website_activation(product, row),
@@ -54,6 +60,49 @@ impl ProductRowActivation {
         });
     }
 
+    fn setup_rows_copy_request(&self) {
+        let this_weak = self.downgrade();
+        self.state.ui.product_page().connect_row_copy_requested(move |product_page, row, row_type| {
+            if
+                row_type == ProductRowType::Website
+                && let Some(this) = this_weak.upgrade()
+                && let Some(product) = this.state.repository.product_by_index(product_page.index() as usize)
+            {
+                this.handle_website_copy(product, row);
+            }
+        });
+    }
+
+    fn setup_open_all_websites(&self) {
+        let this_weak = self.downgrade();
+        self.state.ui.product_page().connect_open_all_websites(move |product_page| {
+            if
+                let Some(this) = this_weak.upgrade()
+                && let Some(product) = this.state.repository.product_by_index(product_page.index() as usize)
+            {
+                this.launch_all_websites(product);
+            }
+        });
+    }
+
+    fn setup_share_requested(&self) {
+        let this_weak = self.downgrade();
+        self.state.ui.product_page().connect_share_requested(move |product_page| {
+            if
+                let Some(this) = this_weak.upgrade()
+                && let Some(product) = this.state.repository.product_by_index(product_page.index() as usize)
+            {
+                this.copy_share_text(product);
+            }
+        });
+    }
+
+    fn copy_share_text(&self, product: &Product) {
+        let window = self.state.ui.window();
+        window.clipboard().set_text(&Repository::product_share_text(product));
+        window.notify("Share text copied");
+    }
+
     fn handle_err(&self, error: &anyhow::Error) {
         self.state.ui.window().notify(&error.to_string());
         eprintln!("Error: {error}");
@@ -65,6 +114,14 @@ impl ProductRowActivation {
         self.launch_uri(website_url);
     }
 
+    fn handle_website_copy(&self, product: &Product, row: &ProductRowWidget) {
+        let website_index = row.index() as usize;
+        let website_url = product.websites[website_index].1;
+        let window = self.state.ui.window();
+        window.clipboard().set_text(website_url);
+        window.notify("Link copied");
+    }
+
     fn launch_uri(&self, uri: &str) {
         let window = self.state.ui.window();
         let this_weak = self.downgrade();
@@ -75,11 +132,65 @@ impl ProductRowActivation {
                 && let Some(this) = this_weak.upgrade()
             {
                 let error = anyhow!("Failed to open website: {uri_owned}").context(e);
-                this.handle_err(&error);
+                this.handle_launch_err(&error, uri_owned);
             }
         });
     }
 
+    fn handle_launch_err(&self, error: &anyhow::Error, uri: String) {
+        eprintln!("Error: {error}");
+        let this_weak = self.downgrade();
+        self.state.ui.window().notify_with_action(&error.to_string(), "Retry", move || {
+            if let Some(this) = this_weak.upgrade() {
+                this.launch_uri(&uri);
+            }
+        });
+    }
+
+    pub fn launch_all_websites(&self, product: &Product) {
+        let urls = product.websites
+            .iter()
+            .filter(|(caption, _)| !ProductActivation::is_source_website(caption))
+            .map(|(_, url)| *url)
+            .collect::<Vec<_>>();
+        let total = urls.len();
+        let pending = Rc::new(Cell::new(total));
+        let failures = Rc::new(RefCell::new(Vec::new()));
+        let window = self.state.ui.window();
+
+        for url in urls {
+            let this_weak = self.downgrade();
+            let pending = pending.clone();
+            let failures = failures.clone();
+            gtk::UriLauncher::new(url).launch(Some(window), None::<&gtk::gio::Cancellable>, move |result| {
+                if let Err(error) = result {
+                    failures.borrow_mut().push(anyhow!("Failed to open website: {url}").context(error));
+                }
+
+                pending.set(pending.get() - 1);
+                if
+                    pending.get() == 0
+                    && let Some(this) = this_weak.upgrade()
+                {
+                    this.report_launch_failures(&failures.borrow(), total);
+                }
+            });
+        }
+    }
+
+    fn report_launch_failures(&self, failures: &[anyhow::Error], total: usize) {
+        if failures.is_empty() {
+            return;
+        }
+
+        for error in failures {
+            eprintln!("Error: {error}");
+        }
+
+        let message = format!("Failed to open {count} of {total} websites", count = failures.len());
+        self.state.ui.window().notify(&message);
+    }
+
     fn handle_category_activation_with_debounce(&self, row: &ProductRowWidget) {
         let category_index = row.index() as usize;
         self.state.ui.navigation().replace_with_page(NavigationPage::Main);
@@ -0,0 +1,163 @@
+use super::super::prelude::*;
+use super::super::repository::Repository;
+use super::super::settings::SettingsStore;
+use super::super::ui::Ui;
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::{Rc, Weak};
+
+const RECENT_PRODUCTS_KEY: &str = "recent-products";
+pub const MAX_RECENT_PRODUCTS: usize = 10;
+
+type ChangedCallback = Box<dyn Fn(&[String]) + 'static>;
+
+struct State {
+    ui: Ui,
+    repository: Repository,
+    settings: SettingsStore,
+    recents: RefCell<VecDeque<String>>,
+    changed_callbacks: RefCell<Vec<ChangedCallback>>
+}
+
+pub struct WeakRecents {
+    state: Weak<State>
+}
+
+impl WeakRecents {
+    pub fn upgrade(&self) -> Option<Recents> {
+        self.state.upgrade().map(|state| Recents { state })
+    }
+}
+
+#[derive(Clone)]
+pub struct Recents {
+    state: Rc<State>
+}
+
+impl Recents {
+
+    pub fn new(ui: Ui, repository: Repository, settings: SettingsStore) -> Self {
+        let mut recents = VecDeque::new();
+        for name in settings.strv(RECENT_PRODUCTS_KEY).into_iter().rev() {
+            Self::push_bounded(&mut recents, name, MAX_RECENT_PRODUCTS);
+        }
+
+        let state = State {
+            ui,
+            repository,
+            settings,
+            recents: RefCell::new(recents),
+            changed_callbacks: RefCell::new(Vec::new())
+        };
+        let this = Self { state: Rc::new(state) };
+        this.setup_product_opened();
+        this
+    }
+
+    fn setup_product_opened(&self) {
+        let this_weak = self.downgrade();
+        self.state.ui.product_page().connect_index_notify(move |product_page| {
+            if
+                let Some(this) = this_weak.upgrade()
+                && let Some(product) = this.state.repository.product_by_index(product_page.index() as usize)
+            {
+                this.push(product.name);
+            }
+        });
+    }
+
+    /// Moves `name` to the front, dropping an earlier entry for the same name
+    /// and the oldest entries past `capacity`.
+    pub fn push_bounded(recents: &mut VecDeque<String>, name: String, capacity: usize) {
+        recents.retain(|recent| *recent != name);
+        recents.push_front(name);
+        recents.truncate(capacity);
+    }
+
+    pub fn push(&self, name: &str) {
+        if self.state.recents.borrow().front().is_some_and(|recent| recent == name) {
+            return;
+        }
+
+        Self::push_bounded(&mut self.state.recents.borrow_mut(), name.to_string(), MAX_RECENT_PRODUCTS);
+
+        if let Err(error) = self.save_recents() {
+            self.state.ui.window().notify(&error.to_string());
+            eprintln!("Error: {error}");
+        }
+
+        let names = self.recent_names();
+        for callback in self.state.changed_callbacks.borrow().iter() {
+            callback(&names);
+        }
+    }
+
+    /// Most recent first.
+    pub fn recent_names(&self) -> Vec<String> {
+        self.state.recents.borrow().iter().cloned().collect()
+    }
+
+    /// Most recent first, skipping names that are no longer in the catalog.
+    pub fn recent_products(&self) -> Vec<usize> {
+        self.state.recents
+            .borrow()
+            .iter()
+            .filter_map(|name| self.state.repository.product_index_by_name_ci(name))
+            .collect()
+    }
+
+    fn save_recents(&self) -> Result<()> {
+        let recents = self.state.recents.borrow();
+        let names = recents.iter().map(String::as_str).collect::<Vec<_>>();
+        self.state.settings.set_strv(RECENT_PRODUCTS_KEY, &names)
+    }
+
+    pub fn connect_changed<F>(&self, callback: F)
+    where
+        F: Fn(&[String]) + 'static
+    {
+        self.state.changed_callbacks.borrow_mut().push(Box::new(callback));
+    }
+
+    pub fn downgrade(&self) -> WeakRecents {
+        let state = Rc::downgrade(&self.state);
+        WeakRecents { state }
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(recents: &VecDeque<String>) -> Vec<&str> {
+        recents.iter().map(String::as_str).collect()
+    }
+
+    #[test]
+    fn newest_name_comes_first() {
+        let mut recents = VecDeque::new();
+        Recents::push_bounded(&mut recents, String::from("Nextcloud"), 3);
+        Recents::push_bounded(&mut recents, String::from("Vivaldi"), 3);
+        assert_eq!(names(&recents), ["Vivaldi", "Nextcloud"]);
+    }
+
+    #[test]
+    fn reopened_name_moves_to_the_front_once() {
+        let mut recents = VecDeque::new();
+        for name in ["Nextcloud", "Vivaldi", "Nextcloud"] {
+            Recents::push_bounded(&mut recents, String::from(name), 3);
+        }
+        assert_eq!(names(&recents), ["Nextcloud", "Vivaldi"]);
+    }
+
+    #[test]
+    fn oldest_names_are_dropped_past_the_capacity() {
+        let mut recents = VecDeque::new();
+        for name in ["Proton Mail", "Nextcloud", "Vivaldi"] {
+            Recents::push_bounded(&mut recents, String::from(name), 2);
+        }
+        assert_eq!(names(&recents), ["Vivaldi", "Nextcloud"]);
+    }
+}
\ No newline at end of file
@@ -1,14 +1,24 @@
-use super::super::search_engine::SearchEngine;
-use super::super::widgets::{SidebarRowWidget, OverviewProductRowWidget, SidebarSearchRowState};
+use super::super::prelude::*;
+use super::super::search_engine::{CategoryMatches, SearchEngine, SearchMode};
+use super::super::settings::SettingsStore;
+use super::super::widgets::{SidebarRowWidget, OverviewProductRowWidget, SidebarSearchRowState, SidebarCountryRowState};
 use super::super::models::Country;
 use super::super::ui::Ui;
+use super::preferences::{MULTI_COUNTRY_FILTER_KEY, SEARCH_MATCH_ANY_KEY};
 
-use std::collections::HashMap;
+use std::cell::Cell;
 use std::rc::{Rc, Weak};
+use std::time::Duration;
+
+const SEARCH_DEBOUNCE_KEY: &str = "search-debounce";
+const DEFAULT_SEARCH_DEBOUNCE_MS: i32 = 150;
 
 struct State {
     ui: Ui,
-    engine: SearchEngine
+    engine: SearchEngine,
+    settings: SettingsStore,
+    pending_update: Cell<Option<glib::SourceId>>,
+    search_debounce: Duration
 }
 
 pub struct WeakSearch {
@@ -28,11 +38,22 @@ pub struct Search {
 
 impl Search {
 
-    pub fn new(ui: Ui, engine: SearchEngine) -> Self {
-        let state = State { ui, engine };
+    pub fn new(ui: Ui, engine: SearchEngine, settings: SettingsStore) -> Self {
+        let search_debounce = Self::search_debounce_from_setting(
+            settings.int(SEARCH_DEBOUNCE_KEY, DEFAULT_SEARCH_DEBOUNCE_MS)
+        );
+        let state = State {
+            ui,
+            engine,
+            settings,
+            pending_update: Cell::new(None),
+            search_debounce
+        };
         let this = Self { state: Rc::new(state) };
+        this.setup_country_selection_mode();
         this.setup_search_text_changed();
         this.setup_country_selection_changed();
+        this.setup_escape_key();
         this
     }
 
@@ -40,58 +61,182 @@ impl Search {
         self.state.ui.search_row().set_state(SidebarSearchRowState::Active)
     }
 
+    pub fn activate_country_filter(&self) {
+        self.state.ui.main_page().show_sidebar();
+
+        // Without a selection, activating the row already opens the dropdown.
+        let country_row = self.state.ui.country_row();
+        country_row.set_state(SidebarCountryRowState::Active);
+        if country_row.has_selection() {
+            country_row.popup();
+        }
+    }
+
+    pub fn clear_filters(&self) {
+        self.state.ui.sidebar().clear_changes();
+        self.state.ui.overview_page().scroll_to_top();
+        self.schedule_search_update();
+    }
+
+    /// Goes back to the previous page. The navigation restores the filters
+    /// that were cleared on it, which triggers a new search.
+    pub fn navigate_back(&self) -> bool {
+        self.state.ui.navigation().pop()
+    }
+
+    // Bubble phase, so the search entry and open popovers handle Escape first.
+    fn setup_escape_key(&self) {
+        let key_controller = gtk::EventControllerKey::new();
+        let this_weak = self.downgrade();
+        key_controller.connect_key_pressed(move |_, key, _, modifiers| {
+            if
+                key == gtk::gdk::Key::Escape
+                && modifiers.is_empty()
+                && let Some(this) = this_weak.upgrade()
+            {
+                this.clear_filters();
+                glib::Propagation::Stop
+            } else {
+                glib::Propagation::Proceed
+            }
+        });
+        self.state.ui.main_page().add_controller(key_controller);
+    }
+
     fn setup_search_text_changed(&self) {
         let this_weak = self.downgrade();
         self.state.ui.search_row().connect_search_changed(move |_, _| {
-            this_weak
-                .upgrade()
-                .is_some_and(|this| this.update_search_results())
+            if let Some(this) = this_weak.upgrade() {
+                this.schedule_search_update();
+            }
         });
     }
 
+    fn setup_country_selection_mode(&self) {
+        let multi_select = self.state.settings.boolean(MULTI_COUNTRY_FILTER_KEY, false);
+        self.state.ui.country_row().set_multi_select(multi_select);
+    }
+
     fn setup_country_selection_changed(&self) {
         let this_weak = self.downgrade();
-        self.state.ui.country_row().connect_item_selected(move |_, _| {
+        self.state.ui.country_row().connect_selection_changed(move |_, _| {
             if let Some(this) = this_weak.upgrade() {
-                this.update_search_results();
+                this.schedule_search_update();
             }
         });
     }
 
+    /// Negative values from a hand-edited key file mean no delay.
+    fn search_debounce_from_setting(milliseconds: i32) -> Duration {
+        Duration::from_millis(u64::try_from(milliseconds).unwrap_or(0))
+    }
+
+    /// Runs the search again, e.g. after a search preference changed.
+    pub fn refresh(&self) {
+        self.schedule_search_update();
+    }
+
+    // Every change restarts the timeout, so only the last change in a burst of
+    // typing runs a filtering pass. The sidebar entry's own search-delay is 0,
+    // leaving this timeout as the only debounce. Results are applied from a main loop callback
+    // so the busy spinner gets a chance to be drawn first.
+    fn schedule_search_update(&self) {
+        if let Some(source_id) = self.state.pending_update.take() {
+            source_id.remove();
+        }
+
+        self.state.ui.search_row().set_busy(true);
+        let this_weak = self.downgrade();
+        let handler = move || {
+            if let Some(this) = this_weak.upgrade() {
+                this.state.pending_update.take();
+                this.apply_search_update();
+            }
+        };
+
+        let source_id = glib::timeout_add_local_once(self.state.search_debounce, handler);
+        self.state.pending_update.set(Some(source_id));
+    }
+
+    fn apply_search_update(&self) {
+        let search_row = self.state.ui.search_row();
+        let has_any_matches = self.update_search_results();
+        search_row.set_successful_search(has_any_matches);
+        search_row.set_busy(false);
+    }
+
     fn update_search_results(&self) -> bool {
         let search_text = self.state.ui.search_row().search_text();
-        let country = self.get_selected_country();
-        let search_results = self.state.engine.find_by_category(&search_text, country);
+        let countries = self.get_selected_countries();
+        let search_results = self.state.engine.find_by_category_auto_country(&search_text, &countries, self.search_mode());
 
         self.update_overview_page(&search_results.by_category);
+        self.update_highlighted_names(&search_text);
         self.update_category_list(&search_results.by_category);
+        self.announce_result_count(search_results.total_matches);
 
         search_results.has_any_matches
     }
 
-    fn get_selected_country(&self) -> Option<Country> {
-        self.state.ui
-            .country_row()
-            .selected_item()
-            .and_then(|item| Country::from_index(item.index() as usize))
+    fn announce_result_count(&self, total_matches: usize) {
+        self.state.ui.overview_page().announce(
+            &Self::result_count_message(total_matches),
+            gtk::AccessibleAnnouncementPriority::Medium,
+        );
     }
 
-    fn update_overview_page(&self, results: &[HashMap<usize, bool>]) {
-        self.state.ui.overview_page().scroll_to_top();
-        self.state.ui.overview_page().groups().iter().for_each(|(_, group)| {
+    pub fn result_count_message(total_matches: usize) -> String {
+        match total_matches {
+            0 => String::from("No products found"),
+            1 => String::from("1 product found"),
+            count => format!("{count} products found"),
+        }
+    }
+
+    fn search_mode(&self) -> SearchMode {
+        if self.state.settings.boolean(SEARCH_MATCH_ANY_KEY, false) {
+            SearchMode::Any
+        } else {
+            SearchMode::All
+        }
+    }
+
+    fn get_selected_countries(&self) -> Vec<Country> {
+        self.state.ui.country_row().selected_countries()
+    }
+
+    fn update_overview_page(&self, results: &[CategoryMatches]) {
+        let overview_page = self.state.ui.overview_page();
+        overview_page.scroll_to_top();
+        overview_page.groups().values().for_each(|group| {
             if let Some(matches) = results.get(group.index() as usize) {
                 group.apply_row_filter(|row: &OverviewProductRowWidget| {
-                    matches.get(&(row.index() as usize)).copied().unwrap_or(false)
+                    matches.is_included(row.index() as usize)
                 });
             }
         });
+        overview_page.refresh_active_group();
     }
 
-    fn update_category_list(&self, results: &[HashMap<usize, bool>]) {
+    fn update_highlighted_names(&self, search_text: &str) {
+        let query = search_text.trim();
+        for group in self.state.ui.overview_page().groups().values() {
+            for row in group.rows().values() {
+                if
+                    (query.is_empty() || row.is_visible())
+                    && let Some(markup) = self.state.engine.highlighted_name(row.index() as usize, query)
+                {
+                    row.set_highlighted_name(&markup);
+                }
+            }
+        }
+    }
+
+    fn update_category_list(&self, results: &[CategoryMatches]) {
         self.state.ui.category_list().apply_row_filter(|row: &SidebarRowWidget| {
             results
                 .get(row.index() as usize)
-                .is_some_and(|matches| matches.values().any(|&v| v))
+                .is_some_and(CategoryMatches::has_matches)
         });
     }
 
@@ -99,4 +244,34 @@ impl Search {
         let state = Rc::downgrade(&self.state);
         WeakSearch { state }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_debounce_defaults_to_150_ms() {
+        assert_eq!(Search::search_debounce_from_setting(DEFAULT_SEARCH_DEBOUNCE_MS), Duration::from_millis(150));
+    }
+
+    #[test]
+    fn search_debounce_follows_the_setting() {
+        assert_eq!(Search::search_debounce_from_setting(400), Duration::from_millis(400));
+        assert_eq!(Search::search_debounce_from_setting(0), Duration::ZERO);
+        assert_eq!(Search::search_debounce_from_setting(-20), Duration::ZERO);
+    }
+
+    fn announced_count(query: &str, mode: SearchMode) -> (String, usize) {
+        let search_engine = SearchEngine::new(crate::test_catalog::repository());
+        let results = search_engine.find_by_category_auto_country(query, &[], mode);
+        (Search::result_count_message(results.total_matches), results.total_matches)
+    }
+
+    #[test]
+    fn announced_count_is_the_total_number_of_matches() {
+        assert_eq!(announced_count("proton nextcloud", SearchMode::Any), (String::from("2 products found"), 2));
+        assert_eq!(announced_count("browser mail", SearchMode::All), (String::from("1 product found"), 1));
+        assert_eq!(announced_count("proton nextcloud", SearchMode::All), (String::from("No products found"), 0));
+    }
 }
\ No newline at end of file
@@ -1,5 +1,6 @@
 use super::super::prelude::*;
 use super::super::widgets::WindowSize as WindowSizeData;
+use super::super::settings::SettingsStore;
 use super::super::ui::Ui;
 
 use std::rc::{Rc, Weak};
@@ -7,10 +8,12 @@ use std::rc::{Rc, Weak};
 const WIDTH_KEY: &str = "window-width";
 const HEIGHT_KEY: &str = "window-height";
 const MAXIMIZED_KEY: &str = "window-maximized";
+const DEFAULT_WIDTH: i32 = 1000;
+const DEFAULT_HEIGHT: i32 = 600;
 
 struct State {
     ui: Ui,
-    settings: gtk::gio::Settings
+    settings: SettingsStore
 }
 
 pub struct WeakWindowSize {
@@ -29,10 +32,11 @@ pub struct WindowSize {
 
 impl WindowSize {
 
-    pub fn new(ui: Ui, settings: gtk::gio::Settings) -> Self {
+    pub fn new(ui: Ui, settings: SettingsStore) -> Self {
         let state = Rc::new(State { ui, settings });
         let controller = Self { state };
         controller.setup_window_size_changed();
+        controller.setup_close_request();
         controller.apply_saved_size();
         controller
     }
@@ -48,11 +52,22 @@ impl WindowSize {
         });
     }
 
+    // Saves once more on close, so a size whose save failed earlier is not lost.
+    fn setup_close_request(&self) {
+        let controller_weak = self.downgrade();
+        self.state.ui.window().connect_close_request(move |window| {
+            if let Some(controller) = controller_weak.upgrade() {
+                let _ = controller.save_window_size(window.current_size());
+            }
+            glib::Propagation::Proceed
+        });
+    }
+
     fn load_saved_size(&self) -> WindowSizeData {
         WindowSizeData {
-            width: self.state.settings.int(WIDTH_KEY).clamp(0, i32::MAX).unsigned_abs(),
-            height: self.state.settings.int(HEIGHT_KEY).clamp(0, i32::MAX).unsigned_abs(),
-            maximized: self.state.settings.boolean(MAXIMIZED_KEY),
+            width: self.state.settings.int(WIDTH_KEY, DEFAULT_WIDTH).clamp(0, i32::MAX).unsigned_abs(),
+            height: self.state.settings.int(HEIGHT_KEY, DEFAULT_HEIGHT).clamp(0, i32::MAX).unsigned_abs(),
+            maximized: self.state.settings.boolean(MAXIMIZED_KEY, false),
         }
     }
 
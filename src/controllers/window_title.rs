@@ -0,0 +1,196 @@
+use super::super::prelude::*;
+use super::super::constants;
+use super::super::ui::Ui;
+use super::super::repository::Repository;
+use super::super::widgets::NavigationPage;
+use super::super::models::Country;
+
+use std::cell::Cell;
+use std::rc::{Rc, Weak};
+
+struct State {
+    ui: Ui,
+    repository: Repository,
+    country: Cell<Option<Country>>
+}
+
+struct WeakWindowTitle {
+    state: Weak<State>
+}
+
+impl WeakWindowTitle {
+    fn upgrade(&self) -> Option<WindowTitle> {
+        self.state.upgrade().map(|state| WindowTitle { state })
+    }
+}
+
+pub struct WindowTitle {
+    state: Rc<State>
+}
+
+impl WindowTitle {
+
+    pub fn new(ui: Ui, repository: Repository) -> Self {
+        let country = Cell::new(ui.country_row().selected_country());
+        let state = State { ui, repository, country };
+        let this = Self { state: Rc::new(state) };
+        this.setup_page_changed();
+        this.setup_active_group_changed();
+        this.setup_search_text_changed();
+        this.setup_country_changed();
+        this.update_title();
+        this
+    }
+
+    /// On the main page a single filtered country follows the search or the
+    /// category, like a breadcrumb.
+    pub fn derive_title(
+        page: Option<NavigationPage>,
+        query: &str,
+        category_name: Option<&str>,
+        country_name: Option<&str>,
+        product_name: Option<&str>
+    ) -> String {
+        let context = match page {
+            Some(NavigationPage::Product) => product_name.map(ToString::to_string),
+            Some(NavigationPage::Main) => {
+                let view = if query.is_empty() {
+                    category_name.map(ToString::to_string)
+                } else {
+                    Some(format!("Search \u{201c}{query}\u{201d}"))
+                };
+                match (view, country_name) {
+                    (Some(view), Some(country_name)) => Some(format!("{view} \u{b7} {country_name}")),
+                    (view, country_name) => view.or_else(|| country_name.map(ToString::to_string)),
+                }
+            },
+            Some(NavigationPage::Comparison) => Some(String::from("Compare Products")),
+            Some(NavigationPage::Loading | NavigationPage::Empty) | None => None,
+        };
+
+        context.map_or_else(
+            || constants::APP_TITLE.to_string(),
+            |context| format!("{context} \u{2014} {title}", title = constants::APP_TITLE)
+        )
+    }
+
+    fn setup_page_changed(&self) {
+        let this_weak = self.downgrade();
+        self.state.ui.navigation().connect_page_changed(move |_, _| {
+            if let Some(this) = this_weak.upgrade() {
+                this.update_title();
+            }
+        });
+    }
+
+    fn setup_active_group_changed(&self) {
+        let this_weak = self.downgrade();
+        self.state.ui.overview_page().connect_active_group_changed(move |_, _| {
+            if let Some(this) = this_weak.upgrade() {
+                this.update_title();
+            }
+        });
+    }
+
+    fn setup_search_text_changed(&self) {
+        let this_weak = self.downgrade();
+        self.state.ui.search_row().connect_text_changed(move |_, _| {
+            if let Some(this) = this_weak.upgrade() {
+                this.update_title();
+            }
+        });
+    }
+
+    fn setup_country_changed(&self) {
+        let this_weak = self.downgrade();
+        self.state.ui.country_row().connect_country_changed(move |_, country| {
+            if let Some(this) = this_weak.upgrade() {
+                this.state.country.set(country);
+                this.update_title();
+            }
+        });
+    }
+
+    fn update_title(&self) {
+        let ui = &self.state.ui;
+        let query = ui.search_row().search_text();
+        let product_name = ui.product_page().name();
+        let category_name = ui
+            .overview_page()
+            .active_group_index()
+            .and_then(|index| self.state.repository.category_by_index(index))
+            .map(|category| category.name);
+
+        let title = Self::derive_title(
+            ui.navigation().page(),
+            query.trim(),
+            category_name,
+            self.state.country.get().map(|country| country.display_name()),
+            Some(product_name.as_str()).filter(|name| !name.is_empty())
+        );
+
+        ui.window().set_title(Some(&title));
+    }
+
+    fn downgrade(&self) -> WeakWindowTitle {
+        let state = Rc::downgrade(&self.state);
+        WeakWindowTitle { state }
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn title(page: Option<NavigationPage>, query: &str) -> String {
+        WindowTitle::derive_title(page, query, Some("Browsers"), None, Some("Vivaldi"))
+    }
+
+    fn with_app_title(context: &str) -> String {
+        format!("{context} \u{2014} {title}", title = constants::APP_TITLE)
+    }
+
+    #[test]
+    fn product_page_shows_the_product_name() {
+        assert_eq!(title(Some(NavigationPage::Product), "mail"), with_app_title("Vivaldi"));
+        assert_eq!(
+            WindowTitle::derive_title(Some(NavigationPage::Product), "", None, None, None),
+            constants::APP_TITLE
+        );
+    }
+
+    #[test]
+    fn main_page_shows_the_query_before_the_category() {
+        assert_eq!(title(Some(NavigationPage::Main), "mail"), with_app_title("Search \u{201c}mail\u{201d}"));
+        assert_eq!(title(Some(NavigationPage::Main), ""), with_app_title("Browsers"));
+    }
+
+    #[test]
+    fn main_page_follows_with_the_filtered_country() {
+        let main = Some(NavigationPage::Main);
+        assert_eq!(
+            WindowTitle::derive_title(main, "", Some("Browsers"), Some("Germany"), None),
+            with_app_title("Browsers \u{b7} Germany")
+        );
+        assert_eq!(
+            WindowTitle::derive_title(main, "mail", Some("Browsers"), Some("Germany"), None),
+            with_app_title("Search \u{201c}mail\u{201d} \u{b7} Germany")
+        );
+        assert_eq!(
+            WindowTitle::derive_title(main, "", None, Some("Germany"), None),
+            with_app_title("Germany")
+        );
+    }
+
+    #[test]
+    fn comparison_page_has_a_fixed_title() {
+        assert_eq!(title(Some(NavigationPage::Comparison), "mail"), with_app_title("Compare Products"));
+    }
+
+    #[test]
+    fn loading_or_no_page_shows_only_the_app_title() {
+        assert_eq!(title(Some(NavigationPage::Loading), "mail"), constants::APP_TITLE);
+        assert_eq!(title(None, "mail"), constants::APP_TITLE);
+    }
+}
\ No newline at end of file
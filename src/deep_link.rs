@@ -0,0 +1,134 @@
+use super::constants;
+use super::repository::Repository;
+
+const PRODUCT_HOST: &str = "product";
+const CATEGORY_HOST: &str = "category";
+
+/// Target of a `european-choice://product/<name>` or
+/// `european-choice://category/<slug>` link, before it is resolved against the catalog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeepLinkTarget<'a> {
+    Product(&'a str),
+    Category(&'a str),
+}
+
+impl<'a> DeepLinkTarget<'a> {
+    pub fn parse_uri(uri: &'a str) -> Option<Self> {
+        let (scheme, rest) = uri.split_once("://")?;
+        if !scheme.eq_ignore_ascii_case(constants::APP_NAME) {
+            return None;
+        }
+
+        let rest = rest.split(['?', '#']).next().unwrap_or_default();
+        let (host, path) = rest.split_once('/')?;
+        let value = path.trim_end_matches('/');
+        if value.is_empty() || value.contains('/') {
+            return None;
+        }
+
+        match host {
+            PRODUCT_HOST => Some(Self::Product(value)),
+            CATEGORY_HOST => Some(Self::Category(value)),
+            _ => None,
+        }
+    }
+
+    pub fn resolve(self, repository: Repository) -> Option<DeepLink> {
+        match self {
+            Self::Product(name) => DeepLink::product(repository, name),
+            Self::Category(slug) => DeepLink::category(repository, slug),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeepLink {
+    Product(usize),
+    Category(usize),
+}
+
+impl DeepLink {
+    /// Products are looked up by name first and by slug second.
+    pub fn product(repository: Repository, name: &str) -> Option<Self> {
+        let name = name.trim();
+        repository
+            .product_index_by_name_ci(name)
+            .or_else(|| repository.product_index_by_slug(name))
+            .map(Self::Product)
+    }
+
+    /// Slugs that match no category fall back to the category with the
+    /// closest name, so slightly mistyped links still open.
+    pub fn category(repository: Repository, slug: &str) -> Option<Self> {
+        let slug = slug.trim();
+        repository
+            .category_index_by_slug(slug)
+            .or_else(|| {
+                repository
+                    .closest_category(&slug.replace('-', " "))
+                    .map(|(category_index, _)| category_index)
+            })
+            .map(Self::Category)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_catalog;
+
+    fn uri(rest: &str) -> String {
+        format!("{scheme}://{rest}", scheme = constants::APP_NAME)
+    }
+
+    #[test]
+    fn product_and_category_uris_parse() {
+        assert_eq!(DeepLinkTarget::parse_uri(&uri("product/Proton Mail")), Some(DeepLinkTarget::Product("Proton Mail")));
+        assert_eq!(DeepLinkTarget::parse_uri(&uri("category/browsers")), Some(DeepLinkTarget::Category("browsers")));
+    }
+
+    #[test]
+    fn trailing_slashes_queries_and_fragments_are_ignored() {
+        assert_eq!(DeepLinkTarget::parse_uri(&uri("category/browsers/")), Some(DeepLinkTarget::Category("browsers")));
+        assert_eq!(DeepLinkTarget::parse_uri(&uri("product/vivaldi?ref=share")), Some(DeepLinkTarget::Product("vivaldi")));
+        assert_eq!(DeepLinkTarget::parse_uri(&uri("product/vivaldi#top")), Some(DeepLinkTarget::Product("vivaldi")));
+        let uppercase_scheme = format!("{scheme}://product/vivaldi", scheme = constants::APP_NAME.to_uppercase());
+        assert_eq!(DeepLinkTarget::parse_uri(&uppercase_scheme), Some(DeepLinkTarget::Product("vivaldi")));
+    }
+
+    #[test]
+    fn malformed_uris_are_rejected() {
+        assert_eq!(DeepLinkTarget::parse_uri("https://product/vivaldi"), None);
+        assert_eq!(DeepLinkTarget::parse_uri("product/vivaldi"), None);
+        assert_eq!(DeepLinkTarget::parse_uri(&uri("product")), None);
+        assert_eq!(DeepLinkTarget::parse_uri(&uri("product/")), None);
+        assert_eq!(DeepLinkTarget::parse_uri(&uri("product/a/b")), None);
+        assert_eq!(DeepLinkTarget::parse_uri(&uri("vendor/vivaldi")), None);
+    }
+
+    #[test]
+    fn products_resolve_by_name_or_slug() {
+        let repository = test_catalog::repository();
+
+        assert_eq!(DeepLinkTarget::Product("proton mail").resolve(repository), Some(DeepLink::Product(0)));
+        assert_eq!(DeepLinkTarget::Product("proton-mail").resolve(repository), Some(DeepLink::Product(0)));
+        assert_eq!(DeepLinkTarget::Product("unknown").resolve(repository), None);
+    }
+
+    #[test]
+    fn categories_resolve_by_slug() {
+        let repository = test_catalog::repository();
+
+        assert_eq!(DeepLink::category(repository, "browsers"), Some(DeepLink::Category(2)));
+        assert_eq!(DeepLink::category(repository, " image-editors "), Some(DeepLink::Category(3)));
+    }
+
+    #[test]
+    fn mistyped_category_slugs_resolve_to_the_closest_name() {
+        let repository = test_catalog::repository();
+
+        assert_eq!(DeepLink::category(repository, "cloud-storag"), Some(DeepLink::Category(0)));
+        assert_eq!(DeepLink::category(repository, "email-provider"), Some(DeepLink::Category(1)));
+        assert_eq!(DeepLink::category(repository, "video-games"), None);
+    }
+}
\ No newline at end of file
@@ -16,7 +16,14 @@ mod ui;
 mod ordered_map;
 mod search_engine;
 mod populator;
+mod settings;
+mod deep_link;
+mod sync_guard;
 mod prelude;
+#[cfg(test)]
+mod test_catalog;
+#[cfg(test)]
+mod test_gtk;
 
 fn main() -> anyhow::Result<()> {
     application::Application::new().run()
@@ -1,12 +1,23 @@
 use super::category::Category;
 use super::product::Product;
 
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
 #[derive(Debug)]
 pub struct Catalog {
+    pub version: u32,
     pub categories: &'static [Category],
     pub products: &'static [Product],
     pub categories_map: phf::Map<&'static str, usize>,
     pub products_map: phf::Map<&'static str, usize>,
+    pub product_slugs_map: phf::Map<&'static str, usize>,
     pub category_products: &'static [&'static [usize]],
     pub country_products: &'static [&'static [usize]],
+    /// `category_products` as sets, built on first use.
+    pub category_member_sets: OnceLock<Vec<HashSet<usize>>>,
+}
+
+impl Catalog {
+    pub const VERSION: u32 = 3;
 }
\ No newline at end of file
@@ -7,4 +7,54 @@ pub struct Category {
     pub description: String,
     pub summary: String,
     pub icon: String,
-}
\ No newline at end of file
+}
+
+impl Category {
+    pub const COLOR_BUCKETS: u16 = 12;
+
+    #[must_use]
+    pub fn color_hue(&self) -> u16 {
+        Self::hue_from_slug(&self.slug)
+    }
+
+    #[must_use]
+    pub fn hue_from_slug(slug: &str) -> u16 {
+        let hash = slug.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+            (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+        });
+        u16::try_from(hash % 360).unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn color_class(&self) -> std::string::String {
+        Self::color_class_from_hue(self.color_hue())
+    }
+
+    #[must_use]
+    pub fn color_class_from_hue(hue: u16) -> std::string::String {
+        format!("category-hue-{bucket}", bucket = hue * Self::COLOR_BUCKETS / 360)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hues_are_derived_from_the_slug() {
+        assert_eq!(Category::hue_from_slug(""), 61);
+        assert_eq!(Category::hue_from_slug("browsers"), 308);
+        assert_eq!(Category::hue_from_slug("image-editors"), 25);
+    }
+
+    #[test]
+    fn color_classes_bucket_the_hue() {
+        let color_class = |slug| Category::color_class_from_hue(Category::hue_from_slug(slug));
+
+        assert_eq!(color_class("browsers"), "category-hue-10");
+        assert_eq!(color_class("email-providers"), "category-hue-8");
+        assert_eq!(color_class("image-editors"), "category-hue-0");
+        assert_eq!(Category::color_class_from_hue(359), "category-hue-11");
+    }
+}
@@ -1,5 +1,5 @@
 macro_rules! define_countries {
-    ($(($variant:ident, $display_name:literal, $slug:literal, $country_code:literal)),* $(,)?) => {
+    ($(($variant:ident, $display_name:literal, $slug:literal, $country_code:literal, $country_code_alpha3:literal, $region:ident, $eu_member:literal, $currency:literal)),* $(,)?) => {
         #[derive(Debug, Clone, Copy, PartialEq, Eq)]
         pub enum Country {
             $($variant,)*
@@ -10,10 +10,11 @@ macro_rules! define_countries {
 
             #[must_use]
             pub fn parse(string: &str) -> Option<Self> {
-                match string {
-                    $($display_name | $country_code => Some(Country::$variant),)*
-                    _ => None,
-                }
+                Self::all().iter().copied().find(|country| {
+                    country.display_name().eq_ignore_ascii_case(string)
+                        || country.code().eq_ignore_ascii_case(string)
+                        || country.code_alpha3().eq_ignore_ascii_case(string)
+                })
             }
 
             #[must_use]
@@ -37,11 +38,76 @@ macro_rules! define_countries {
                 }
             }
 
+            #[must_use]
+            pub const fn code_alpha3(&self) -> &'static str {
+                match self {
+                    $(Country::$variant => $country_code_alpha3,)*
+                }
+            }
+
+            #[must_use]
+            pub fn flag_emoji(&self) -> String {
+                const REGIONAL_INDICATOR_OFFSET: u32 = 0x1F1E6 - 'a' as u32;
+
+                self.code()
+                    .chars()
+                    .map(|letter| letter.to_ascii_lowercase() as u32 + REGIONAL_INDICATOR_OFFSET)
+                    .filter_map(char::from_u32)
+                    .collect()
+            }
+
+            #[must_use]
+            pub const fn region(&self) -> Region {
+                match self {
+                    $(Country::$variant => Region::$region,)*
+                }
+            }
+
+            #[must_use]
+            pub fn in_region(region: Region) -> Vec<Self> {
+                Self::all()
+                    .iter()
+                    .copied()
+                    .filter(|country| country.region() == region)
+                    .collect()
+            }
+
+            #[must_use]
+            pub const fn is_eu_member(&self) -> bool {
+                match self {
+                    $(Country::$variant => $eu_member,)*
+                }
+            }
+
+            /// Region, EU membership and currency, e.g. "Central Europe · EU member · EUR".
+            #[must_use]
+            pub fn details(&self) -> String {
+                let membership = if self.is_eu_member() { "EU member" } else { "Outside the EU" };
+                format!("{region} · {membership} · {currency}", region = self.region(), currency = self.currency())
+            }
+
+            #[must_use]
+            pub const fn currency(&self) -> &'static str {
+                match self {
+                    $(Country::$variant => $currency,)*
+                }
+            }
+
             #[must_use]
             pub const fn all() -> &'static [Self; Self::COUNT] {
                 &[$(Country::$variant,)*]
             }
 
+            #[must_use]
+            #[cfg_attr(all(runtime, not(test)), expect(dead_code))]
+            pub fn eu_members() -> Vec<Self> {
+                Self::all()
+                    .iter()
+                    .copied()
+                    .filter(Self::is_eu_member)
+                    .collect()
+            }
+
             #[must_use]
             pub const fn from_index(index: usize) -> Option<Self> {
                 if index < Self::COUNT {
@@ -69,40 +135,188 @@ macro_rules! define_countries {
     };
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Region {
+    Nordic,
+    Baltic,
+    Western,
+    Central,
+    Southern,
+    Eastern,
+}
+
+impl Region {
+    #[must_use]
+    pub const fn all() -> &'static [Self] {
+        &[Self::Nordic, Self::Baltic, Self::Western, Self::Central, Self::Southern, Self::Eastern]
+    }
+
+    #[must_use]
+    pub const fn display_name(&self) -> &'static str {
+        match self {
+            Self::Nordic => "Nordics",
+            Self::Baltic => "Baltics",
+            Self::Western => "Western Europe",
+            Self::Central => "Central Europe",
+            Self::Southern => "Southern Europe",
+            Self::Eastern => "Eastern Europe",
+        }
+    }
+}
+
+impl std::fmt::Display for Region {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "{display_name}", display_name = self.display_name())
+    }
+}
+
 macro_rules! count_items {
     () => { 0 };
     ($head:tt $($tail:tt)*) => { 1 + count_items!($($tail)*) };
 }
 
 define_countries! {
-    (Austria, "Austria", "austria", "at"),
-    (Belgium, "Belgium", "belgium", "be"),
-    (Bulgaria, "Bulgaria", "bulgaria", "bg"),
-    (Croatia, "Croatia", "croatia", "hr"),
-    (Cyprus, "Cyprus", "cyprus", "cy"),
-    (CzechRepublic, "Czech Republic", "czech_republic", "cz"),
-    (Denmark, "Denmark", "denmark", "dk"),
-    (Estonia, "Estonia", "estonia", "ee"),
-    (Finland, "Finland", "finland", "fi"),
-    (France, "France", "france", "fr"),
-    (Germany, "Germany", "germany", "de"),
-    (Greece, "Greece", "greece", "gr"),
-    (Hungary, "Hungary", "hungary", "hu"),
-    (Ireland, "Ireland", "ireland", "ie"),
-    (Italy, "Italy", "italy", "it"),
-    (Latvia, "Latvia", "latvia", "lv"),
-    (Lithuania, "Lithuania", "lithuania", "lt"),
-    (Luxembourg, "Luxembourg", "luxembourg", "lu"),
-    (Malta, "Malta", "malta", "mt"),
-    (Netherlands, "Netherlands", "netherlands", "nl"),
-    (Poland, "Poland", "poland", "pl"),
-    (Portugal, "Portugal", "portugal", "pt"),
-    (Romania, "Romania", "romania", "ro"),
-    (Slovakia, "Slovakia", "slovakia", "sk"),
-    (Slovenia, "Slovenia", "slovenia", "si"),
-    (Spain, "Spain", "spain", "es"),
-    (Sweden, "Sweden", "sweden", "se"),
-    (Switzerland, "Switzerland", "switzerland", "ch"),
-    (UnitedKingdom, "United Kingdom", "united_kingdom", "gb"),
-    (Ukraine, "Ukraine", "ukraine", "ua")
+    (Austria, "Austria", "austria", "at", "aut", Central, true, "EUR"),
+    (Belgium, "Belgium", "belgium", "be", "bel", Western, true, "EUR"),
+    (Bulgaria, "Bulgaria", "bulgaria", "bg", "bgr", Eastern, true, "EUR"),
+    (Croatia, "Croatia", "croatia", "hr", "hrv", Southern, true, "EUR"),
+    (Cyprus, "Cyprus", "cyprus", "cy", "cyp", Southern, true, "EUR"),
+    (CzechRepublic, "Czech Republic", "czech_republic", "cz", "cze", Central, true, "CZK"),
+    (Denmark, "Denmark", "denmark", "dk", "dnk", Nordic, true, "DKK"),
+    (Estonia, "Estonia", "estonia", "ee", "est", Baltic, true, "EUR"),
+    (Finland, "Finland", "finland", "fi", "fin", Nordic, true, "EUR"),
+    (France, "France", "france", "fr", "fra", Western, true, "EUR"),
+    (Germany, "Germany", "germany", "de", "deu", Central, true, "EUR"),
+    (Greece, "Greece", "greece", "gr", "grc", Southern, true, "EUR"),
+    (Hungary, "Hungary", "hungary", "hu", "hun", Central, true, "HUF"),
+    (Ireland, "Ireland", "ireland", "ie", "irl", Western, true, "EUR"),
+    (Italy, "Italy", "italy", "it", "ita", Southern, true, "EUR"),
+    (Latvia, "Latvia", "latvia", "lv", "lva", Baltic, true, "EUR"),
+    (Lithuania, "Lithuania", "lithuania", "lt", "ltu", Baltic, true, "EUR"),
+    (Luxembourg, "Luxembourg", "luxembourg", "lu", "lux", Western, true, "EUR"),
+    (Malta, "Malta", "malta", "mt", "mlt", Southern, true, "EUR"),
+    (Netherlands, "Netherlands", "netherlands", "nl", "nld", Western, true, "EUR"),
+    (Poland, "Poland", "poland", "pl", "pol", Central, true, "PLN"),
+    (Portugal, "Portugal", "portugal", "pt", "prt", Southern, true, "EUR"),
+    (Romania, "Romania", "romania", "ro", "rou", Eastern, true, "RON"),
+    (Slovakia, "Slovakia", "slovakia", "sk", "svk", Central, true, "EUR"),
+    (Slovenia, "Slovenia", "slovenia", "si", "svn", Central, true, "EUR"),
+    (Spain, "Spain", "spain", "es", "esp", Southern, true, "EUR"),
+    (Sweden, "Sweden", "sweden", "se", "swe", Nordic, true, "SEK"),
+    (Switzerland, "Switzerland", "switzerland", "ch", "che", Central, false, "CHF"),
+    (UnitedKingdom, "United Kingdom", "united_kingdom", "gb", "gbr", Western, false, "GBP"),
+    (Ukraine, "Ukraine", "ukraine", "ua", "ukr", Eastern, false, "UAH")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_parse_ignoring_case() {
+        for code in ["de", "DE", "De", "deu", "DEU", "Deu"] {
+            assert_eq!(Country::parse(code), Some(Country::Germany), "code {code:?}");
+        }
+    }
+
+    #[test]
+    fn display_names_parse_ignoring_case() {
+        assert_eq!(Country::parse("Germany"), Some(Country::Germany));
+        assert_eq!(Country::parse("germany"), Some(Country::Germany));
+        assert_eq!(Country::parse("CZECH REPUBLIC"), Some(Country::CzechRepublic));
+        assert_eq!("united kingdom".parse(), Ok(Country::UnitedKingdom));
+    }
+
+    #[test]
+    fn unknown_strings_do_not_parse() {
+        assert_eq!(Country::parse(""), None);
+        assert_eq!(Country::parse("ger"), None);
+        assert_eq!(Country::parse("germany "), None);
+        assert_eq!(Country::parse("us"), None);
+    }
+
+    #[test]
+    fn every_country_parses_from_its_columns() {
+        for &country in Country::all() {
+            assert_eq!(Country::parse(country.display_name()), Some(country));
+            assert_eq!(Country::parse(country.code()), Some(country));
+            assert_eq!(Country::parse(country.code_alpha3()), Some(country));
+        }
+    }
+
+    #[test]
+    fn regions_group_known_countries() {
+        assert_eq!(Country::Sweden.region(), Region::Nordic);
+        assert_eq!(Country::Latvia.region(), Region::Baltic);
+        assert_eq!(Country::Germany.region(), Region::Central);
+        assert_eq!(Country::Portugal.region(), Region::Southern);
+        assert_eq!(
+            Country::in_region(Region::Nordic),
+            [Country::Denmark, Country::Finland, Country::Sweden]
+        );
+    }
+
+    #[test]
+    fn every_country_belongs_to_one_region() {
+        for &country in Country::all() {
+            let regions = Region::all()
+                .iter()
+                .filter(|&&region| Country::in_region(region).contains(&country))
+                .count();
+            assert_eq!(regions, 1, "{country}");
+        }
+
+        let total = Region::all().iter().map(|&region| Country::in_region(region).len()).sum::<usize>();
+        assert_eq!(total, Country::COUNT);
+    }
+
+    #[test]
+    fn eu_membership_excludes_three_countries() {
+        assert_eq!(Country::eu_members().len(), 27);
+
+        for country in [Country::Switzerland, Country::UnitedKingdom, Country::Ukraine] {
+            assert!(!country.is_eu_member(), "{country}");
+        }
+        assert!(Country::Germany.is_eu_member());
+    }
+
+    #[test]
+    fn details_describe_region_membership_and_currency() {
+        assert_eq!(Country::Germany.details(), "Central Europe · EU member · EUR");
+        assert_eq!(Country::UnitedKingdom.details(), "Western Europe · Outside the EU · GBP");
+    }
+
+    #[test]
+    fn currencies_use_iso_codes() {
+        assert_eq!(Country::Sweden.currency(), "SEK");
+        assert_eq!(Country::France.currency(), "EUR");
+        assert_eq!(Country::Switzerland.currency(), "CHF");
+
+        for &country in Country::all() {
+            let currency = country.currency();
+            assert_eq!(currency.len(), 3, "{country}");
+            assert!(currency.chars().all(|letter| letter.is_ascii_uppercase()), "{country}");
+        }
+    }
+
+    #[test]
+    fn flags_are_built_from_the_country_code() {
+        assert_eq!(Country::Germany.flag_emoji(), "\u{1F1E9}\u{1F1EA}");
+        assert_eq!(Country::Germany.flag_emoji().len(), 8);
+        assert_eq!(Country::UnitedKingdom.flag_emoji(), "\u{1F1EC}\u{1F1E7}");
+
+        for &country in Country::all() {
+            assert_eq!(country.flag_emoji().chars().count(), 2, "{country}");
+        }
+    }
+
+    #[test]
+    fn indices_match_the_declaration_order() {
+        assert_eq!(Country::all().len(), Country::COUNT);
+        for (index, &country) in Country::all().iter().enumerate() {
+            assert_eq!(Country::from_index(index), Some(country));
+            assert_eq!(country as usize, index);
+        }
+        assert_eq!(Country::from_index(Country::COUNT), None);
+    }
 }
\ No newline at end of file
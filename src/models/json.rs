@@ -0,0 +1,41 @@
+use std::fmt::Write;
+
+/// `value` as a quoted JSON string, for both the catalog written at build
+/// time and the catalog export.
+#[must_use]
+pub fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for character in value.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            character if character.is_control() => {
+                write!(escaped, "\\u{code:04x}", code = character as u32).unwrap();
+            }
+            character => escaped.push(character),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quotes_plain_text() {
+        assert_eq!(escape_json("Proton Mail"), "\"Proton Mail\"");
+    }
+
+    #[test]
+    fn escapes_quotes_backslashes_and_control_characters() {
+        assert_eq!(escape_json("a \"b\" \\ c"), r#""a \"b\" \\ c""#);
+        assert_eq!(escape_json("line\nbreak\ttab"), r#""line\nbreak\ttab""#);
+        assert_eq!(escape_json("\u{1}"), r#""\u0001""#);
+    }
+}
\ No newline at end of file
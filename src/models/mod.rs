@@ -2,6 +2,7 @@ mod country;
 mod product;
 mod category;
 mod catalog;
+mod json;
 
 #[cfg(runtime)]
 type String = &'static str;
@@ -18,7 +19,8 @@ type Array<T> = &'static[T];
 #[cfg(not(runtime))]
 type Array<T> = Vec<T>;
 
-pub use self::country::Country;
+pub use self::country::{Country, Region};
 pub use self::product::Product;
 pub use self::category::Category;
-pub use self::catalog::Catalog;
\ No newline at end of file
+pub use self::catalog::Catalog;
+pub use self::json::escape_json;
\ No newline at end of file
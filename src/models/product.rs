@@ -4,8 +4,9 @@ use super::{Categories, String, Array};
 #[derive(Debug, Clone)]
 pub struct Product {
     pub categories: Categories,
-    pub logo: String,
+    pub logo: Option<String>,
     pub name: String,
+    pub slug: String,
     pub description: String,
     pub summary: String,
     pub country: Option<Country>,
@@ -16,4 +17,53 @@ impl AsRef<Self> for Product {
     fn as_ref(&self) -> &Self {
         self
     }
-}
\ No newline at end of file
+}
+
+impl Product {
+    #[must_use]
+    pub const fn has_logo(&self) -> bool {
+        self.logo.is_some()
+    }
+
+    #[must_use]
+    pub fn initials(&self) -> std::string::String {
+        Self::initials_from_name(&self.name)
+    }
+
+    #[must_use]
+    pub fn initials_from_name(name: &str) -> std::string::String {
+        name.split_whitespace()
+            .filter_map(|word| word.chars().find(|character| character.is_alphanumeric()))
+            .take(2)
+            .flat_map(char::to_uppercase)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multi_word_names_use_the_first_two_words() {
+        assert_eq!(Product::initials_from_name("Proton Mail"), "PM");
+        assert_eq!(Product::initials_from_name("proton mail bridge"), "PM");
+    }
+
+    #[test]
+    fn single_word_names_use_one_letter() {
+        assert_eq!(Product::initials_from_name("Nextcloud"), "N");
+    }
+
+    #[test]
+    fn leading_punctuation_is_skipped() {
+        assert_eq!(Product::initials_from_name("(Open) Office"), "OO");
+        assert_eq!(Product::initials_from_name("- Photopea"), "P");
+    }
+
+    #[test]
+    fn empty_names_have_no_initials() {
+        assert_eq!(Product::initials_from_name(""), "");
+        assert_eq!(Product::initials_from_name("  --  "), "");
+    }
+}
@@ -26,6 +26,29 @@ impl<T> OrderedMap<T> {
         })
     }
 
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (usize, &mut T)> {
+        let mut values = self.map
+            .iter_mut()
+            .map(|(&key, value)| (key, value))
+            .collect::<HashMap<_, _>>();
+
+        self.indices.iter().filter_map(move |&index| {
+            values.remove(&index).map(|value| (index, value))
+        })
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = usize> {
+        self.indices.iter().copied()
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.indices.iter().filter_map(|index| self.map.get(index))
+    }
+
+    pub fn contains_key(&self, key: usize) -> bool {
+        self.map.contains_key(&key)
+    }
+
     pub fn insert(&mut self, key: usize, value: T) -> Option<T> {
         if !self.map.contains_key(&key) {
             self.indices.push(key);
@@ -52,6 +75,17 @@ impl<T> OrderedMap<T> {
         }
     }
 
+    pub fn retain<F: FnMut(usize, &T) -> bool>(&mut self, mut predicate: F) {
+        let map = &mut self.map;
+        self.indices.retain(|&key| {
+            let keep = map.get(&key).is_some_and(|value| predicate(key, value));
+            if !keep {
+                map.remove(&key);
+            }
+            keep
+        });
+    }
+
 
     pub const fn len(&self) -> usize {
         self.indices.len()
@@ -72,4 +106,131 @@ impl<T> OrderedMap<T> {
             self.map.get(&key).map(|value| (key, value))
         })
     }
+}
+
+impl<T> FromIterator<(usize, T)> for OrderedMap<T> {
+    fn from_iter<I: IntoIterator<Item = (usize, T)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<T> Extend<(usize, T)> for OrderedMap<T> {
+    fn extend<I: IntoIterator<Item = (usize, T)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letters() -> OrderedMap<char> {
+        let mut map = OrderedMap::new();
+        map.insert(3, 'c');
+        map.insert(1, 'a');
+        map.insert(2, 'b');
+        map
+    }
+
+    #[test]
+    fn keys_and_values_keep_insertion_order() {
+        let map = letters();
+
+        assert_eq!(map.keys().collect::<Vec<_>>(), [3, 1, 2]);
+        assert_eq!(map.values().copied().collect::<Vec<_>>(), ['c', 'a', 'b']);
+    }
+
+    #[test]
+    fn replacing_a_value_keeps_its_position() {
+        let mut map = letters();
+
+        assert_eq!(map.insert(1, 'z'), Some('a'));
+        assert_eq!(map.keys().collect::<Vec<_>>(), [3, 1, 2]);
+        assert_eq!(map.values().copied().collect::<Vec<_>>(), ['c', 'z', 'b']);
+    }
+
+    #[test]
+    fn contains_key_follows_inserts_and_removes() {
+        let mut map = letters();
+        assert!(map.contains_key(1));
+        assert!(!map.contains_key(4));
+
+        map.insert(4, 'd');
+        assert!(map.contains_key(4));
+
+        assert_eq!(map.remove(1), Some('a'));
+        assert!(!map.contains_key(1));
+        assert_eq!(map.keys().collect::<Vec<_>>(), [3, 2, 4]);
+    }
+
+    #[test]
+    fn retain_removes_the_first_middle_and_last_entries() {
+        for (removed, expected) in [(3, [1, 2]), (1, [3, 2]), (2, [3, 1])] {
+            let mut map = letters();
+            map.retain(|key, _| key != removed);
+
+            assert_eq!(map.len(), 2);
+            assert_eq!(map.keys().collect::<Vec<_>>(), expected);
+            assert!(!map.contains_key(removed));
+            assert_eq!(map.get(removed), None);
+        }
+    }
+
+    #[test]
+    fn retain_passes_keys_and_values() {
+        let mut map = letters();
+        map.retain(|key, &value| key == 3 || value == 'b');
+
+        assert_eq!(map.iter().collect::<Vec<_>>(), [(3, &'c'), (2, &'b')]);
+
+        map.retain(|_, _| false);
+        assert!(map.is_empty());
+        assert_eq!(map.first(), None);
+    }
+
+    #[test]
+    fn iter_mut_yields_in_insertion_order() {
+        let mut map = letters();
+        let keys = map.iter_mut().map(|(key, _)| key).collect::<Vec<_>>();
+
+        assert_eq!(keys, [3, 1, 2]);
+    }
+
+    #[test]
+    fn iter_mut_changes_are_read_back() {
+        let mut map = letters();
+        for (key, value) in map.iter_mut() {
+            *value = value.to_ascii_uppercase();
+            if key == 1 {
+                *value = 'x';
+            }
+        }
+
+        assert_eq!(map.values().copied().collect::<Vec<_>>(), ['C', 'x', 'B']);
+        assert_eq!(map.get_by_index(1), Some(&'x'));
+    }
+
+    #[test]
+    fn collecting_keeps_the_source_order() {
+        let map = [(5, "e"), (0, "a"), (9, "i")].into_iter().collect::<OrderedMap<_>>();
+
+        assert_eq!(map.keys().collect::<Vec<_>>(), [5, 0, 9]);
+        assert_eq!(map.first(), Some((5, &"e")));
+        assert_eq!(map.last(), Some((9, &"i")));
+    }
+
+    #[test]
+    fn duplicate_keys_replace_values_in_place() {
+        let mut map = [(1, "a"), (2, "b"), (1, "c")].into_iter().collect::<OrderedMap<_>>();
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.iter().collect::<Vec<_>>(), [(1, &"c"), (2, &"b")]);
+
+        map.extend([(3, "d"), (2, "e")]);
+        assert_eq!(map.iter().collect::<Vec<_>>(), [(1, &"c"), (2, &"e"), (3, &"d")]);
+    }
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use super::repository::Repository;
+use super::repository::{CategorySort, ProductSort, Repository};
 use super::models::Category;
 use super::ui::Ui;
 use super::widgets::{
@@ -12,24 +12,41 @@ pub struct Populator {}
 
 impl Populator {
 
-    pub fn populate(ui: &Ui, repository: Repository) {
-        let categories = repository.categories_sorted();
+    /// Without categories there is nothing to group products under, so that
+    /// counts as empty as much as having no products.
+    pub fn is_catalog_empty(repository: Repository) -> bool {
+        repository.categories().is_empty() || repository.products().is_empty()
+    }
+
+    pub fn populate(ui: &Ui, repository: Repository, product_sort: ProductSort) -> bool {
+        Self::populate_with_sort(ui, repository, CategorySort::default(), product_sort)
+    }
+
+    /// The sidebar and the overview share the same category order, while
+    /// `product_sort` orders the products within each overview group.
+    pub fn populate_with_sort(ui: &Ui, repository: Repository, sort: CategorySort, product_sort: ProductSort) -> bool {
+        let Some(categories) = Self::categories_to_populate(&repository, sort) else {
+            ui.show_empty_catalog();
+            return false;
+        };
 
         Self::populate_sidebar_country_row(ui, repository);
         Self::populate_sidebar_category_list(ui, &categories);
-        Self::populate_overview(ui, repository, &categories);
+        Self::populate_overview(ui, repository, &categories, product_sort);
+        true
+    }
+
+    /// Categories in the order they are added, or `None` for an empty catalog,
+    /// which gets the empty status page instead.
+    fn categories_to_populate(repository: &Repository, sort: CategorySort) -> Option<Vec<(usize, &Category)>> {
+        (!Self::is_catalog_empty(*repository)).then(|| repository.categories_sorted_by(sort))
     }
 
     fn populate_sidebar_country_row(ui: &Ui, repository: Repository) {
         let country_row = ui.country_row();
-        for country in Repository::countries_sorted() {
-            if repository
-                .product_indices_by_country(country)
-                .is_some_and(|indices| !indices.is_empty())
-            {
-                let item = SidebarCountryItemWidget::from_country(country);
-                country_row.add_item(&item);
-            }
+        for country in repository.countries_with_products() {
+            let item = SidebarCountryItemWidget::from_country(country);
+            country_row.add_item(&item);
         }
     }
 
@@ -41,9 +58,9 @@ impl Populator {
         }
     }
 
-    fn populate_overview(ui: &Ui, repository: Repository, categories: &[(usize, &Category)]) {
+    fn populate_overview(ui: &Ui, repository: Repository, categories: &[(usize, &Category)], product_sort: ProductSort) {
         for (category_index, category) in categories {
-            if let Some(products_indices) = repository.category_products_sorted(category) {
+            if let Some(products_indices) = repository.category_products_sorted_by(category, product_sort) {
                 let group = OverviewProductGroupWidget::from_category(category, *category_index);
 
                 for (product_index, product) in products_indices {
@@ -55,4 +72,59 @@ impl Populator {
             }
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Catalog, Country, Product};
+    use crate::test_catalog;
+
+    #[test]
+    fn empty_catalog_is_not_populated() {
+        let repository = test_catalog::empty_repository();
+
+        assert!(Populator::is_catalog_empty(repository));
+        assert!(Populator::categories_to_populate(&repository, CategorySort::default()).is_none());
+    }
+
+    static UNCATEGORIZED_CATALOG: Catalog = Catalog {
+        version: Catalog::VERSION,
+        categories: &[],
+        products: &[Product {
+            categories: &[],
+            logo: None,
+            name: "Vivaldi",
+            slug: "vivaldi",
+            description: "Web browser.",
+            summary: "Web browser.",
+            country: None,
+            websites: &[],
+        }],
+        categories_map: phf::phf_map! {},
+        products_map: phf::phf_map! { "Vivaldi" => 0 },
+        product_slugs_map: phf::phf_map! { "vivaldi" => 0 },
+        category_products: &[],
+        country_products: &[&[]; Country::COUNT],
+        category_member_sets: std::sync::OnceLock::new(),
+    };
+
+    #[test]
+    fn catalog_without_categories_is_not_populated() {
+        let repository = Repository::new(&UNCATEGORIZED_CATALOG);
+
+        assert!(!repository.products().is_empty());
+        assert!(Populator::is_catalog_empty(repository));
+        assert!(Populator::categories_to_populate(&repository, CategorySort::default()).is_none());
+    }
+
+    #[test]
+    fn catalog_with_products_is_populated_in_sort_order() {
+        let repository = test_catalog::repository();
+        let categories = Populator::categories_to_populate(&repository, CategorySort::Slug).unwrap();
+        let indices = categories.iter().map(|(index, _)| *index).collect::<Vec<_>>();
+
+        assert!(!Populator::is_catalog_empty(repository));
+        assert_eq!(indices, [2, 0, 1, 3]);
+    }
 }
\ No newline at end of file
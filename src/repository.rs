@@ -1,7 +1,57 @@
-use crate::constants::APP_CATALOG;
+use crate::constants::{APP_CATALOG, APP_SOURCE_NAME};
 
 use super::models::{Catalog, Category, Product, Country};
 
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// A closest category may differ by one edit for every this many characters
+/// of its name, so short names only accept exact or nearly exact matches.
+const CLOSEST_CATEGORY_CHARACTERS_PER_EDIT: usize = 4;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CategorySort {
+    #[default]
+    Slug,
+    Name,
+    ProductCountDesc,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProductSort {
+    #[default]
+    Name,
+    CountryThenName,
+    CountryOnly,
+}
+
+impl ProductSort {
+    pub const fn all() -> &'static [Self] {
+        &[Self::Name, Self::CountryThenName, Self::CountryOnly]
+    }
+
+    /// Value of the `product-sort` setting.
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::CountryThenName => "country-then-name",
+            Self::CountryOnly => "country-only",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::all().iter().copied().find(|sort| sort.key() == key)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CatalogStats {
+    pub total_products: usize,
+    pub total_categories: usize,
+    pub countries_represented: usize,
+    pub average_products_per_category: f64,
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct Repository {
     catalog: &'static Catalog,
@@ -22,13 +72,69 @@ impl Repository {
         countries
     }
 
+    pub fn countries_with_products(&self) -> Vec<Country> {
+        Self::countries_sorted()
+            .into_iter()
+            .filter(|country| {
+                self.product_indices_by_country(*country)
+                    .is_some_and(|indices| !indices.is_empty())
+            })
+            .collect()
+    }
+
+    pub fn stats(&self) -> CatalogStats {
+        let total_products = self.catalog.products.len();
+        let total_categories = self.catalog.categories.len();
+        let countries_represented = self.catalog
+            .country_products
+            .iter()
+            .filter(|product_indices| !product_indices.is_empty())
+            .count();
+        let category_memberships = self.category_counts().iter().sum::<usize>();
+        let average_products_per_category = if total_categories == 0 {
+            0.0
+        } else {
+            category_memberships as f64 / total_categories as f64
+        };
+
+        CatalogStats {
+            total_products,
+            total_categories,
+            countries_represented,
+            average_products_per_category,
+        }
+    }
+
     pub fn categories_sorted(&self) -> Vec<(usize, &Category)> {
+        self.categories_sorted_by(CategorySort::Slug)
+    }
+
+    /// Ties in product count fall back to slug order so the result is stable.
+    pub fn categories_sorted_by(&self, sort: CategorySort) -> Vec<(usize, &Category)> {
         let mut categories = self.catalog.categories.iter().enumerate().collect::<Vec<_>>();
-        categories.sort_by(|(_, category_a), (_, category_b)| category_a.slug.cmp(category_b.slug));
+        match sort {
+            CategorySort::Slug => {
+                categories.sort_by(|(_, category_a), (_, category_b)| category_a.slug.cmp(category_b.slug));
+            }
+            CategorySort::Name => {
+                categories.sort_by(|(_, category_a), (_, category_b)| category_a.name.cmp(category_b.name));
+            }
+            CategorySort::ProductCountDesc => {
+                categories.sort_by(|(index_a, category_a), (index_b, category_b)| {
+                    self.category_product_count(*index_b)
+                        .cmp(&self.category_product_count(*index_a))
+                        .then_with(|| category_a.slug.cmp(category_b.slug))
+                });
+            }
+        }
         categories
     }
 
     pub fn category_products_sorted(&self, category: &Category) -> Option<Vec<(usize, &Product)>> {
+        self.category_products_sorted_by(category, ProductSort::Name)
+    }
+
+    pub fn category_products_sorted_by(&self, category: &Category, sort: ProductSort) -> Option<Vec<(usize, &Product)>> {
         if let Some(category_index) = self.catalog.categories_map.get(category.slug).copied()
             && let Some(product_indices) = self.catalog.category_products.get(category_index)
         {
@@ -38,13 +144,35 @@ impl Repository {
                 .map(|product_index| (product_index, &self.catalog.products[product_index]))
                 .collect::<Vec<_>>();
 
-            products.sort_by(|(_, product_a), (_, product_b)| product_a.name.cmp(product_b.name));
+            Self::sort_products(&mut products, sort);
             Some(products)
         } else {
             None
         }
     }
 
+    /// Country sorts compare display names and put products without a country last.
+    fn sort_products(products: &mut [(usize, &Product)], sort: ProductSort) {
+        let country_key = |product: &Product| {
+            product.country.map_or((true, ""), |country| (false, country.display_name()))
+        };
+        match sort {
+            ProductSort::Name => {
+                products.sort_by(|(_, product_a), (_, product_b)| product_a.name.cmp(product_b.name));
+            }
+            ProductSort::CountryThenName => {
+                products.sort_by(|(_, product_a), (_, product_b)| {
+                    country_key(product_a)
+                        .cmp(&country_key(product_b))
+                        .then_with(|| product_a.name.cmp(product_b.name))
+                });
+            }
+            ProductSort::CountryOnly => {
+                products.sort_by(|(_, product_a), (_, product_b)| country_key(product_a).cmp(&country_key(product_b)));
+            }
+        }
+    }
+
     pub const fn categories(&self) -> &[Category] {
         self.catalog.categories
     }
@@ -53,6 +181,29 @@ impl Repository {
         self.catalog.products
     }
 
+    /// Sub-slice starting at `offset` with at most `limit` items, empty when
+    /// `offset` is past the end.
+    fn page<T>(items: &[T], offset: usize, limit: usize) -> &[T] {
+        let start = offset.min(items.len());
+        let end = start.saturating_add(limit).min(items.len());
+        &items[start..end]
+    }
+
+    #[cfg_attr(not(test), expect(dead_code))]
+    pub fn products_page(&self, offset: usize, limit: usize) -> &[Product] {
+        Self::page(self.products(), offset, limit)
+    }
+
+    #[cfg_attr(not(test), expect(dead_code))]
+    pub fn category_products_page(&self, category: &Category, offset: usize, limit: usize) -> &[usize] {
+        self.product_indices_by_category(category)
+            .map_or(&[], |indices| Self::page(indices, offset, limit))
+    }
+
+    pub fn products_enumerated(&self) -> impl Iterator<Item = (usize, &Product)> {
+        self.catalog.products.iter().enumerate()
+    }
+
     pub fn category_index_by_slug(self, slug: &str) -> Option<usize> {
         self.catalog.categories_map.get(slug).copied()
     }
@@ -61,6 +212,82 @@ impl Repository {
         self.catalog.products_map.get(name).copied()
     }
 
+    /// Exact lookup first, falling back to a case-insensitive scan.
+    pub fn product_index_by_name_ci(self, name: &str) -> Option<usize> {
+        self.product_index_by_name(name).or_else(|| {
+            let name = name.to_lowercase();
+            self.catalog
+                .products
+                .iter()
+                .position(|product| product.name.to_lowercase() == name)
+        })
+    }
+
+    pub fn product_index_by_slug(self, slug: &str) -> Option<usize> {
+        self.catalog.product_slugs_map.get(slug).copied()
+    }
+
+    pub fn closest_category(&self, name: &str) -> Option<(usize, &Category)> {
+        let name = name.trim().to_lowercase();
+        self.catalog
+            .categories
+            .iter()
+            .enumerate()
+            .map(|(index, category)| {
+                let distance = Self::edit_distance(&name, &category.name.to_lowercase());
+                (distance, index, category)
+            })
+            .filter(|(distance, _, category)| *distance <= Self::closest_category_max_distance(category.name))
+            .min_by_key(|(distance, _, _)| *distance)
+            .map(|(_, index, category)| (index, category))
+    }
+
+    fn closest_category_max_distance(category_name: &str) -> usize {
+        category_name.chars().count() / CLOSEST_CATEGORY_CHARACTERS_PER_EDIT
+    }
+
+    /// One-line summary of a product for sharing, linking the company website
+    /// when there is one and the source listing otherwise.
+    pub fn product_share_text(product: &Product) -> String {
+        let mut text = format!("{name} — {summary}", name = product.name, summary = product.summary);
+
+        if let Some(country) = product.country {
+            write!(text, " ({flag} {country})", flag = country.flag_emoji(), country = country.display_name()).unwrap();
+        }
+
+        let website = product.websites
+            .iter()
+            .find(|(caption, _)| *caption != APP_SOURCE_NAME)
+            .or_else(|| product.websites.first());
+        if let Some((_, url)) = website {
+            write!(text, ": {url}").unwrap();
+        }
+
+        text
+    }
+
+    fn edit_distance(source: &str, target: &str) -> usize {
+        let target_chars = target.chars().collect::<Vec<_>>();
+        let mut previous_row = (0..=target_chars.len()).collect::<Vec<_>>();
+
+        for (source_index, source_char) in source.chars().enumerate() {
+            let mut current_row = Vec::with_capacity(previous_row.len());
+            current_row.push(source_index + 1);
+
+            for (target_index, target_char) in target_chars.iter().enumerate() {
+                let substitution_cost = usize::from(source_char != *target_char);
+                let distance = (previous_row[target_index] + substitution_cost)
+                    .min(previous_row[target_index + 1] + 1)
+                    .min(current_row[target_index] + 1);
+                current_row.push(distance);
+            }
+
+            previous_row = current_row;
+        }
+
+        previous_row[target_chars.len()]
+    }
+
     pub fn product_by_index(&self, index: usize) -> Option<&Product> {
         self.catalog.products.get(index)
     }
@@ -75,16 +302,437 @@ impl Repository {
             .and_then(|category_index| self.catalog.category_products.get(*category_index).copied())
     }
 
+    pub fn category_product_count(&self, category_index: usize) -> usize {
+        self.catalog
+            .category_products
+            .get(category_index)
+            .map_or(0, |product_indices| product_indices.len())
+    }
+
+    pub fn category_counts(&self) -> Vec<usize> {
+        self.catalog
+            .category_products
+            .iter()
+            .map(|product_indices| product_indices.len())
+            .collect()
+    }
+
+    pub fn product_categories_by_size(&self, product: &Product) -> Vec<usize> {
+        let mut category_indices = product.categories.to_vec();
+        category_indices.sort_by_key(|&category_index| self.category_product_count(category_index));
+        category_indices
+    }
+
+    pub fn product_categories(&self, product_index: usize) -> Vec<&Category> {
+        self.product_by_index(product_index)
+            .map(|product| {
+                product.categories
+                    .iter()
+                    .filter_map(|&category_index| self.category_by_index(category_index))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Catalogs are static, so each one builds its sets once and every later
+    /// call reads them without locking.
+    fn category_member_sets(&self) -> &'static [HashSet<usize>] {
+        self.catalog.category_member_sets.get_or_init(|| {
+            self.catalog
+                .category_products
+                .iter()
+                .map(|product_indices| product_indices.iter().copied().collect())
+                .collect()
+        })
+    }
+
+    /// Products of a category as a set, for constant-time membership checks.
+    pub fn category_member_set(&self, category_index: usize) -> Option<&'static HashSet<usize>> {
+        self.category_member_sets().get(category_index)
+    }
+
     pub fn product_indices_by_country(&self, country: Country) -> Option<&[usize]> {
         self.catalog
             .country_products
             .get(country as usize)
             .copied()
     }
+
+    pub fn products_without_country(&self) -> Vec<usize> {
+        self.products_enumerated()
+            .filter(|(_, product)| product.country.is_none())
+            .map(|(product_index, _)| product_index)
+            .collect()
+    }
+
+    /// Returns (products with a country, total products).
+    pub fn country_coverage(&self) -> (usize, usize) {
+        let total = self.catalog.products.len();
+        (total - self.products_without_country().len(), total)
+    }
+
+    /// Products in both the category and the country, in the order of the
+    /// smaller list. Empty when either lookup is missing.
+    #[cfg_attr(not(test), expect(dead_code))]
+    pub fn product_indices_by_category_and_country(&self, category: &Category, country: Country) -> Vec<usize> {
+        let (Some(category_indices), Some(country_indices)) = (
+            self.product_indices_by_category(category),
+            self.product_indices_by_country(country),
+        ) else {
+            return Vec::new();
+        };
+
+        let (smaller, larger) = if category_indices.len() <= country_indices.len() {
+            (category_indices, country_indices)
+        } else {
+            (country_indices, category_indices)
+        };
+
+        let larger_set = larger.iter().copied().collect::<HashSet<_>>();
+        smaller
+            .iter()
+            .copied()
+            .filter(|product_index| larger_set.contains(product_index))
+            .collect()
+    }
 }
 
 impl Default for Repository {
     fn default() -> Self {
         Self::new(&APP_CATALOG)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_catalog;
+
+    fn category(index: usize) -> &'static Category {
+        &test_catalog::CATALOG.categories[index]
+    }
+
+    #[test]
+    fn stats_aggregate_the_catalog() {
+        let stats = test_catalog::repository().stats();
+
+        assert_eq!(stats.total_products, 4);
+        assert_eq!(stats.total_categories, 4);
+        assert_eq!(stats.countries_represented, 3);
+        assert!((stats.average_products_per_category - 1.25).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn closest_category_accepts_near_misses() {
+        let repository = test_catalog::repository();
+
+        assert_eq!(repository.closest_category("Browsers").map(|(index, _)| index), Some(2));
+        assert_eq!(repository.closest_category("browser").map(|(index, _)| index), Some(2));
+        assert_eq!(repository.closest_category(" Image Editor ").map(|(index, _)| index), Some(3));
+        assert_eq!(repository.closest_category("Emial Providers").map(|(index, _)| index), Some(1));
+    }
+
+    #[test]
+    fn closest_category_distance_scales_with_the_name() {
+        assert_eq!(Repository::closest_category_max_distance("VPN"), 0);
+        assert_eq!(Repository::closest_category_max_distance("Browsers"), 2);
+        assert_eq!(Repository::closest_category_max_distance("Email Providers"), 3);
+    }
+
+    #[test]
+    fn closest_category_rejects_far_misses() {
+        let repository = test_catalog::repository();
+
+        assert!(repository.closest_category("Video Games").is_none());
+        assert!(repository.closest_category("Cloud").is_none());
+        assert!(repository.closest_category("xyz").is_none());
+        assert!(repository.closest_category("Bowsr").is_none());
+        assert!(repository.closest_category("").is_none());
+    }
+
+    #[test]
+    fn names_resolve_exactly_first() {
+        let repository = test_catalog::repository();
+
+        assert_eq!(repository.product_index_by_name_ci("Proton Mail"), Some(0));
+        assert_eq!(repository.product_index_by_name_ci("Nextcloud"), Some(1));
+    }
+
+    #[test]
+    fn names_resolve_ignoring_case() {
+        let repository = test_catalog::repository();
+
+        assert_eq!(repository.product_index_by_name_ci("proton mail"), Some(0));
+        assert_eq!(repository.product_index_by_name_ci("PROTON MAIL"), Some(0));
+        assert_eq!(repository.product_index_by_name_ci("NextCloud"), Some(1));
+        assert_eq!(repository.product_index_by_name("NextCloud"), None);
+    }
+
+    #[test]
+    fn unknown_names_do_not_resolve() {
+        let repository = test_catalog::repository();
+
+        assert_eq!(repository.product_index_by_name_ci("Proton"), None);
+        assert_eq!(repository.product_index_by_name_ci("proton-mail"), None);
+        assert_eq!(repository.product_index_by_name_ci(""), None);
+    }
+
+    #[test]
+    fn slugs_resolve_to_their_products() {
+        let repository = test_catalog::repository();
+
+        assert_eq!(repository.product_index_by_slug("proton-mail"), Some(0));
+        assert_eq!(repository.product_index_by_slug("photopea"), Some(3));
+        assert_eq!(repository.product_index_by_slug("Proton Mail"), None);
+        assert_eq!(repository.product_index_by_slug("unknown"), None);
+    }
+
+    #[test]
+    fn category_and_country_lookups_intersect() {
+        let repository = test_catalog::repository();
+
+        assert_eq!(repository.product_indices_by_category_and_country(category(1), Country::Switzerland), [0]);
+        assert_eq!(repository.product_indices_by_category_and_country(category(0), Country::Germany), [1]);
+        assert_eq!(repository.product_indices_by_category_and_country(category(3), Country::CzechRepublic), [3]);
+    }
+
+    #[test]
+    fn disjoint_category_and_country_lookups_are_empty() {
+        let repository = test_catalog::repository();
+        let unknown_category = Category {
+            slug: "unknown",
+            name: "Unknown",
+            description: "",
+            summary: "",
+            icon: "",
+        };
+
+        assert!(repository.product_indices_by_category_and_country(category(1), Country::Germany).is_empty());
+        assert!(repository.product_indices_by_category_and_country(category(2), Country::France).is_empty());
+        assert!(repository.product_indices_by_category_and_country(&unknown_category, Country::Germany).is_empty());
+    }
+
+    #[test]
+    fn products_without_a_country_are_listed() {
+        let repository = test_catalog::repository();
+
+        assert_eq!(repository.products_without_country(), [2]);
+        assert_eq!(repository.country_coverage(), (3, 4));
+    }
+
+    fn sorted_category_indices(sort: CategorySort) -> Vec<usize> {
+        test_catalog::repository()
+            .categories_sorted_by(sort)
+            .into_iter()
+            .map(|(category_index, _)| category_index)
+            .collect()
+    }
+
+    #[test]
+    fn categories_sort_by_slug_by_default() {
+        assert_eq!(CategorySort::default(), CategorySort::Slug);
+        assert_eq!(sorted_category_indices(CategorySort::Slug), [2, 0, 1, 3]);
+        assert_eq!(
+            test_catalog::repository().categories_sorted().into_iter().map(|(index, _)| index).collect::<Vec<_>>(),
+            [2, 0, 1, 3]
+        );
+    }
+
+    #[test]
+    fn categories_sort_by_name() {
+        assert_eq!(sorted_category_indices(CategorySort::Name), [2, 0, 1, 3]);
+    }
+
+    #[test]
+    fn categories_sort_by_product_count_then_slug() {
+        assert_eq!(sorted_category_indices(CategorySort::ProductCountDesc), [1, 2, 0, 3]);
+    }
+
+    fn product(name: &'static str, country: Option<Country>) -> Product {
+        Product {
+            categories: &[],
+            logo: None,
+            name,
+            slug: "",
+            description: "",
+            summary: "",
+            country,
+            websites: &[],
+        }
+    }
+
+    #[test]
+    fn product_sort_keys_match_the_schema_choices() {
+        let keys = ProductSort::all().iter().map(|sort| sort.key()).collect::<Vec<_>>();
+        assert_eq!(keys, ["name", "country-then-name", "country-only"]);
+        for &sort in ProductSort::all() {
+            assert_eq!(ProductSort::from_key(sort.key()), Some(sort));
+        }
+        assert_eq!(ProductSort::from_key("country"), None);
+    }
+
+    fn sorted_product_names(sort: ProductSort) -> Vec<&'static str> {
+        let products = [
+            product("Alpha", None),
+            product("Beta", Some(Country::Switzerland)),
+            product("Gamma", Some(Country::Germany)),
+            product("Delta", Some(Country::Germany)),
+        ];
+        let mut entries = products.iter().enumerate().collect::<Vec<_>>();
+        Repository::sort_products(&mut entries, sort);
+        entries.into_iter().map(|(_, product)| product.name).collect()
+    }
+
+    #[test]
+    fn products_sort_by_name_by_default() {
+        assert_eq!(ProductSort::default(), ProductSort::Name);
+        assert_eq!(sorted_product_names(ProductSort::Name), ["Alpha", "Beta", "Delta", "Gamma"]);
+    }
+
+    #[test]
+    fn products_sort_by_country_then_name_with_unknown_countries_last() {
+        assert_eq!(sorted_product_names(ProductSort::CountryThenName), ["Delta", "Gamma", "Beta", "Alpha"]);
+    }
+
+    #[test]
+    fn products_sort_by_country_only_keeping_their_order() {
+        assert_eq!(sorted_product_names(ProductSort::CountryOnly), ["Gamma", "Delta", "Beta", "Alpha"]);
+    }
+
+    #[test]
+    fn category_products_sort_within_the_category() {
+        let repository = test_catalog::repository();
+        let sorted = repository
+            .category_products_sorted_by(category(1), ProductSort::CountryThenName)
+            .unwrap()
+            .into_iter()
+            .map(|(product_index, _)| product_index)
+            .collect::<Vec<_>>();
+
+        assert_eq!(sorted, [0, 2]);
+    }
+
+    fn page_names(offset: usize, limit: usize) -> Vec<&'static str> {
+        test_catalog::repository()
+            .products_page(offset, limit)
+            .iter()
+            .map(|product| product.name)
+            .collect()
+    }
+
+    #[test]
+    fn product_pages_clamp_to_the_catalog() {
+        assert_eq!(page_names(0, 2), ["Proton Mail", "Nextcloud"]);
+        assert_eq!(page_names(3, 2), ["Photopea"]);
+        assert_eq!(page_names(1, usize::MAX), ["Nextcloud", "Vivaldi", "Photopea"]);
+    }
+
+    #[test]
+    fn product_pages_past_the_end_or_without_a_limit_are_empty() {
+        assert!(page_names(4, 2).is_empty());
+        assert!(page_names(usize::MAX, 2).is_empty());
+        assert!(page_names(0, 0).is_empty());
+    }
+
+    #[test]
+    fn category_product_pages_clamp_to_the_category() {
+        let repository = test_catalog::repository();
+
+        assert_eq!(repository.category_products_page(category(1), 1, 5), [2]);
+        assert!(repository.category_products_page(category(1), 2, 5).is_empty());
+        assert!(repository.category_products_page(category(1), 0, 0).is_empty());
+    }
+
+    #[test]
+    fn category_member_sets_match_category_products() {
+        let repository = test_catalog::repository();
+
+        for (category_index, product_indices) in test_catalog::CATALOG.category_products.iter().enumerate() {
+            let member_set = repository.category_member_set(category_index).unwrap();
+            assert_eq!(member_set.len(), product_indices.len());
+            assert!(product_indices.iter().all(|product_index| member_set.contains(product_index)));
+        }
+        assert!(repository.category_member_set(4).is_none());
+    }
+
+    #[test]
+    fn category_member_sets_are_built_once_per_catalog() {
+        let first = test_catalog::repository().category_member_set(1).unwrap();
+        let second = test_catalog::repository().category_member_set(1).unwrap();
+
+        assert!(std::ptr::eq(first, second));
+    }
+
+    #[test]
+    fn product_categories_sort_smallest_first() {
+        let repository = test_catalog::repository();
+        let vivaldi = &test_catalog::CATALOG.products[2];
+
+        assert_eq!(repository.product_categories_by_size(vivaldi), [2, 1]);
+    }
+
+    #[test]
+    fn category_counts_match_category_products() {
+        let repository = test_catalog::repository();
+        let expected = test_catalog::CATALOG.category_products
+            .iter()
+            .map(|product_indices| product_indices.len())
+            .collect::<Vec<_>>();
+
+        assert_eq!(repository.category_counts(), expected);
+        for (category_index, count) in expected.iter().enumerate() {
+            assert_eq!(repository.category_product_count(category_index), *count);
+        }
+        assert_eq!(repository.category_product_count(4), 0);
+    }
+
+    #[test]
+    fn countries_with_products_are_sorted_by_slug() {
+        assert_eq!(
+            test_catalog::repository().countries_with_products(),
+            [Country::CzechRepublic, Country::Germany, Country::Switzerland]
+        );
+    }
+
+    #[test]
+    fn product_categories_resolve_in_product_order() {
+        let repository = test_catalog::repository();
+        let slugs = repository.product_categories(2)
+            .iter()
+            .map(|category| category.slug)
+            .collect::<Vec<_>>();
+
+        assert_eq!(slugs, ["browsers", "email-providers"]);
+        assert!(repository.product_categories(4).is_empty());
+    }
+
+    #[test]
+    fn share_text_without_country_or_website_is_just_the_summary() {
+        let vivaldi = &test_catalog::CATALOG.products[2];
+
+        assert_eq!(Repository::product_share_text(vivaldi), "Vivaldi — Web browser.");
+    }
+
+    #[test]
+    fn share_text_prefers_the_company_website_over_the_source_link() {
+        let proton_mail = &test_catalog::CATALOG.products[0];
+
+        assert_eq!(
+            Repository::product_share_text(proton_mail),
+            format!(
+                "Proton Mail — End-to-end encrypted email. ({flag} Switzerland): https://proton.me/mail",
+                flag = Country::Switzerland.flag_emoji()
+            )
+        );
+    }
+
+    #[test]
+    fn enumerated_products_match_their_indices() {
+        let repository = test_catalog::repository();
+
+        assert_eq!(repository.products_enumerated().count(), test_catalog::CATALOG.products.len());
+        for (index, product) in repository.products_enumerated() {
+            assert!(std::ptr::eq(product, repository.product_by_index(index).unwrap()));
+        }
+    }
+}
@@ -1,23 +1,197 @@
-use crate::models::{Country, Product};
+use crate::models::{Country, Product, Region};
 use crate::repository::Repository;
-use std::collections::{BTreeSet, HashMap};
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashSet, VecDeque};
 use std::rc::Rc;
 
 const MIN_TOKEN_LENGTH: usize = 3;
+const MIN_SUB_TOKEN_LENGTH: usize = 3;
+/// Parts split at punctuation may be shorter, so "node.js" still yields "js".
+const MIN_PUNCTUATION_SUB_TOKEN_LENGTH: usize = 2;
+pub const DEFAULT_CACHE_CAPACITY: usize = 16;
+const NAME_WEIGHT: f32 = 4.0;
+const SUMMARY_WEIGHT: f32 = 2.0;
+const DESCRIPTION_WEIGHT: f32 = 1.0;
+const NAME_WORD_PREFIX_BONUS: f32 = 3.0;
+const NAME_PREFIX_BONUS: f32 = 6.0;
+
+pub type Scorer = Box<dyn Fn(&Product, &[String]) -> f32>;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SearchMode {
+    #[default]
+    All,
+    Any,
+}
+
+/// Products of one category and whether the search included each of them.
+/// Both sets are shared rather than copied, so categorizing a search costs
+/// nothing per category.
+pub struct CategoryMatches {
+    members: Option<&'static HashSet<usize>>,
+    matched_products: Rc<BTreeSet<usize>>,
+}
+
+impl CategoryMatches {
+    pub fn is_included(&self, product_index: usize) -> bool {
+        self.members.is_some_and(|members| members.contains(&product_index))
+            && self.matched_products.contains(&product_index)
+    }
+
+    pub fn has_matches(&self) -> bool {
+        self.members.is_some_and(|members| {
+            if members.len() < self.matched_products.len() {
+                members.iter().any(|product_index| self.matched_products.contains(product_index))
+            } else {
+                self.matched_products.iter().any(|product_index| members.contains(product_index))
+            }
+        })
+    }
+}
 
 pub struct CategorizedProductMatches {
-    pub by_category: Vec<HashMap<usize, bool>>,
-    pub has_any_matches: bool
+    pub by_category: Vec<CategoryMatches>,
+    pub has_any_matches: bool,
+    pub total_matches: usize
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GlobalResults {
+    pub products: Vec<usize>,
+    pub categories: Vec<usize>,
+    pub countries: Vec<Country>,
+}
+
+impl GlobalResults {
+    pub const fn is_empty(&self) -> bool {
+        self.products.is_empty() && self.categories.is_empty() && self.countries.is_empty()
+    }
+}
+
+/// Whole words match a query token in either direction, while pieces split out
+/// of a word only match query tokens that are a prefix of them, so short pieces
+/// like "end" from "end-to-end" aren't found inside unrelated queries.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct Tokens {
+    words: Vec<String>,
+    sub_tokens: Vec<String>,
+}
+
+impl Tokens {
+    fn contains(&self, token: &str) -> bool {
+        self.words.iter().chain(&self.sub_tokens).any(|existing| existing == token)
+    }
+
+    fn push_sub_token(&mut self, token: &str, min_length: usize) {
+        if token.chars().count() >= min_length && !self.contains(token) {
+            self.sub_tokens.push(token.to_string());
+        }
+    }
+
+    fn into_query_tokens(self) -> Vec<String> {
+        let mut tokens = self.words;
+        tokens.extend(self.sub_tokens);
+        tokens
+    }
 }
 
 struct SearchIndex {
     repository: Repository,
-    product_tokens: Vec<Vec<String>>,
+    extended_tokens: bool,
+    product_tokens: Vec<Tokens>,
+    /// Every word of every product, so a query token can be checked against
+    /// them without walking the catalog.
+    product_words: HashSet<String>,
+    scorer: Scorer,
+}
+
+type QueryKey = (String, Vec<Country>, SearchMode);
+
+struct LastQuery {
+    query: String,
+    tokens: Vec<String>,
+    countries: Vec<Country>,
+    mode: SearchMode,
+    matches: BTreeSet<usize>,
+}
+
+impl LastQuery {
+    // Only an `All` query can be narrowed, and only when every previous token
+    // survives in the new query, either unchanged or extended by more letters
+    // ("sig" → "sign"). Anything matching the longer token matches its prefix,
+    // unless it matched because the token contains one of its words, which
+    // `find_matching_products` rules out before narrowing.
+    fn is_narrowed_by(&self, query: &str, tokens: &[String], countries: &[Country], mode: SearchMode) -> bool {
+        mode == SearchMode::All
+            && self.mode == mode
+            && self.countries == countries
+            && query.starts_with(self.query.as_str())
+            && self.tokens
+                .iter()
+                .all(|token| tokens.iter().any(|new_token| new_token.starts_with(token.as_str())))
+    }
+}
+
+struct QueryCache {
+    capacity: usize,
+    entries: VecDeque<(QueryKey, BTreeSet<usize>)>,
+    last_query: Option<LastQuery>,
+}
+
+impl QueryCache {
+    const fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: VecDeque::new(),
+            last_query: None,
+        }
+    }
+
+    fn get(&mut self, key: &QueryKey) -> Option<BTreeSet<usize>> {
+        let position = self.entries.iter().position(|(entry_key, _)| entry_key == key)?;
+        let entry = self.entries.remove(position)?;
+        let matches = entry.1.clone();
+        self.entries.push_back(entry);
+        Some(matches)
+    }
+
+    fn insert(&mut self, key: QueryKey, matches: BTreeSet<usize>) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        self.entries.retain(|(entry_key, _)| *entry_key != key);
+        while self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+        }
+
+        self.entries.push_back((key, matches));
+    }
+
+    fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.entries.len() > capacity {
+            self.entries.pop_front();
+        }
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.last_query = None;
+    }
+
+    fn narrowing_candidates(&self, query: &str, tokens: &[String], countries: &[Country], mode: SearchMode) -> Option<BTreeSet<usize>> {
+        self.last_query
+            .as_ref()
+            .filter(|last_query| last_query.is_narrowed_by(query, tokens, countries, mode))
+            .map(|last_query| last_query.matches.clone())
+    }
 }
 
 #[derive(Clone)]
 pub struct SearchEngine {
-    index: Rc<SearchIndex>
+    index: Rc<RefCell<SearchIndex>>,
+    cache: Rc<RefCell<QueryCache>>,
 }
 
 impl SearchEngine {
@@ -34,19 +208,92 @@ impl SearchEngine {
         normalized.split_whitespace().collect::<Vec<_>>().join(" ")
     }
 
-    fn tokenize(text: &str) -> Vec<String> {
-        let normalized = Self::normalize_text(text);
-        let mut tokens = Vec::new();
+    /// With `extended`, short words containing digits ("s3", "o3") are kept
+    /// and words are also split at camelCase and letter-digit boundaries, so
+    /// `NextCloud` adds "next" and "cloud".
+    fn tokenize_with(text: &str, extended: bool) -> Tokens {
+        let mut tokens = Tokens::default();
+
+        for original_word in text.split_whitespace() {
+            let lowercase_word = original_word.to_lowercase();
+            let word = lowercase_word.as_str();
+            let word = word.trim_matches(|character: char| {
+                matches!(character, '.' | ',' | ';' | ':' | '!' | '?' | '(' | ')' | '"' | '\'')
+            });
+            let joined = word.chars().filter(|character| character.is_alphanumeric()).collect::<String>();
+            let joined_length = joined.chars().count();
+            let word_length = word.chars().count();
+
+            if joined_length >= MIN_TOKEN_LENGTH {
+                tokens.words.push(joined);
+            } else if joined_length < word_length && word_length >= MIN_TOKEN_LENGTH {
+                tokens.words.push(word.to_string());
+            } else if extended && joined.contains(|character: char| character.is_numeric()) {
+                tokens.words.push(joined);
+            }
 
-        for word in normalized.split_whitespace() {
-            if word.len() >= MIN_TOKEN_LENGTH {
-                tokens.push(word.to_string());
+            if word.contains(|character: char| !character.is_alphanumeric()) {
+                Self::push_sub_tokens(word, &mut tokens);
+            }
+
+            if extended {
+                Self::push_boundary_tokens(original_word, &mut tokens);
             }
         }
 
         tokens
     }
 
+    fn split_word_boundaries(word: &str) -> Vec<String> {
+        let mut parts = Vec::new();
+        let mut current = String::new();
+        let mut previous: Option<char> = None;
+
+        for character in word.chars() {
+            if !character.is_alphanumeric() {
+                parts.extend((!current.is_empty()).then(|| std::mem::take(&mut current)));
+                previous = None;
+                continue;
+            }
+
+            if let Some(previous) = previous {
+                let is_boundary = (previous.is_lowercase() && character.is_uppercase())
+                    || (previous.is_alphabetic() && character.is_numeric())
+                    || (previous.is_numeric() && character.is_alphabetic());
+                if is_boundary {
+                    parts.push(std::mem::take(&mut current));
+                }
+            }
+
+            current.extend(character.to_lowercase());
+            previous = Some(character);
+        }
+
+        parts.extend((!current.is_empty()).then_some(current));
+        parts
+    }
+
+    fn push_boundary_tokens(word: &str, tokens: &mut Tokens) {
+        let parts = Self::split_word_boundaries(word);
+        if parts.len() < 2 {
+            return;
+        }
+
+        for part in parts {
+            tokens.push_sub_token(&part, MIN_SUB_TOKEN_LENGTH);
+        }
+    }
+
+    fn query_tokens(&self, query: &str) -> Vec<String> {
+        Self::tokenize_with(query, self.index.borrow().extended_tokens).into_query_tokens()
+    }
+
+    fn push_sub_tokens(word: &str, tokens: &mut Tokens) {
+        for part in word.split(|character: char| !character.is_alphanumeric()) {
+            tokens.push_sub_token(part, MIN_PUNCTUATION_SUB_TOKEN_LENGTH);
+        }
+    }
+
     fn build_product_search_text(product: &Product, repository: Repository) -> String {
         let mut parts = Vec::new();
         parts.push(product.name);
@@ -67,96 +314,1035 @@ impl SearchEngine {
         parts.join(" ")
     }
 
-    fn build_product_tokens(repository: Repository) -> Vec<Vec<String>> {
-        let mut product_tokens = Vec::new();
+    fn build_product_tokens(repository: Repository, extended: bool) -> Vec<Tokens> {
+        repository
+            .products()
+            .iter()
+            .map(|product| Self::tokenize_with(&Self::build_product_search_text(product, repository), extended))
+            .collect()
+    }
 
-        for product in repository.products() {
-            let search_text = Self::build_product_search_text(product, repository);
-            let tokens = Self::tokenize(&search_text);
-            product_tokens.push(tokens);
-        }
+    fn token_matches_product(query_token: &str, product_tokens: &Tokens) -> bool {
+        product_tokens
+            .words
+            .iter()
+            .any(|product_token| product_token.contains(query_token) || query_token.contains(product_token.as_str()))
+            || product_tokens
+                .sub_tokens
+                .iter()
+                .any(|product_token| product_token.starts_with(query_token))
+    }
 
+    /// Whether any query token contains a shorter word of some product, the one
+    /// way a longer token can match a product its prefix did not ("webmail"
+    /// matching "mail" after "web").
+    ///
+    /// Looks up the shorter substrings of each token, so the cost depends on
+    /// the token lengths rather than on the catalog size.
+    fn contains_product_word(&self, query_tokens: &[String]) -> bool {
+        let index = self.index.borrow();
+        query_tokens.iter().any(|query_token| {
+            let boundaries = query_token
+                .char_indices()
+                .map(|(position, _)| position)
+                .chain([query_token.len()])
+                .collect::<Vec<_>>();
+            boundaries.iter().enumerate().any(|(start_position, &start)| {
+                boundaries[start_position + 1..]
+                    .iter()
+                    .filter(|&&end| end - start < query_token.len())
+                    .any(|&end| index.product_words.contains(&query_token[start..end]))
+            })
+        })
+    }
+
+    fn collect_product_words(product_tokens: &[Tokens]) -> HashSet<String> {
         product_tokens
+            .iter()
+            .flat_map(|tokens| tokens.words.iter().cloned())
+            .collect()
     }
 
-    fn product_matches_query(&self, product_index: usize, query_tokens: &[String]) -> bool {
+    fn product_matches_query(&self, product_index: usize, query_tokens: &[String], mode: SearchMode) -> bool {
         if query_tokens.is_empty() {
             return true;
         }
 
-        let product_tokens = &self.index.product_tokens[product_index];
+        let index = self.index.borrow();
+        let Some(product_tokens) = index.product_tokens.get(product_index) else {
+            return false;
+        };
 
-        'outer: for query_token in query_tokens {
-            for product_token in product_tokens {
-                if product_token.contains(query_token) || query_token.contains(product_token) {
-                    continue 'outer;
+        match mode {
+            SearchMode::All => query_tokens
+                .iter()
+                .all(|query_token| Self::token_matches_product(query_token, product_tokens)),
+            SearchMode::Any => query_tokens
+                .iter()
+                .any(|query_token| Self::token_matches_product(query_token, product_tokens)),
+        }
+    }
+
+    /// Unlike `product_matches_query`, every token has to be one of the
+    /// product's own, so "switzerland" isn't found through a word like "and".
+    fn product_mentions(&self, product_index: usize, tokens: &[String]) -> bool {
+        self.index
+            .borrow()
+            .product_tokens
+            .get(product_index)
+            .is_some_and(|product_tokens| tokens.iter().all(|token| product_tokens.contains(token)))
+    }
+
+    fn name_prefix_bonus(name: &str, query_tokens: &[String]) -> f32 {
+        if query_tokens.is_empty() {
+            return 0.0;
+        }
+
+        let mut bonus = 0.0;
+        if name.starts_with(query_tokens.join(" ").as_str()) {
+            bonus += NAME_PREFIX_BONUS;
+        }
+
+        for query_token in query_tokens {
+            if name.split_whitespace().any(|word| word.starts_with(query_token.as_str())) {
+                bonus += NAME_WORD_PREFIX_BONUS;
+            }
+        }
+
+        bonus
+    }
+
+    fn field_weighted_score(product: &Product, query_tokens: &[String]) -> f32 {
+        let name = Self::normalize_text(product.name);
+        let name_bonus = Self::name_prefix_bonus(&name, query_tokens);
+        let fields = [
+            (name, NAME_WEIGHT),
+            (Self::normalize_text(product.summary), SUMMARY_WEIGHT),
+            (Self::normalize_text(product.description), DESCRIPTION_WEIGHT),
+        ];
+
+        let mut score = 0.0;
+        for query_token in query_tokens {
+            for (text, weight) in &fields {
+                if text.contains(query_token.as_str()) {
+                    score += weight;
                 }
             }
-            return false;
         }
 
-        true
+        score + name_bonus
     }
 
-    fn find_matching_products(&self, query: &str) -> BTreeSet<usize> {
-        let query_tokens = Self::tokenize(query);
-        let mut matching_products = BTreeSet::new();
+    fn case_insensitive_match_end(text: &str, start: usize, token: &str) -> Option<usize> {
+        let mut token_characters = token.chars().peekable();
+        for (offset, character) in text[start..].char_indices() {
+            for lowercase in character.to_lowercase() {
+                if token_characters.next() != Some(lowercase) {
+                    return None;
+                }
+            }
 
-        for product_index in 0..self.index.repository.products().len() {
-            if self.product_matches_query(product_index, &query_tokens) {
-                matching_products.insert(product_index);
+            if token_characters.peek().is_none() {
+                return Some(start + offset + character.len_utf8());
             }
         }
 
-        matching_products
+        None
     }
 
-    fn categorize_products(&self, matched_products: &BTreeSet<usize>, country_filter: Option<Country>) -> CategorizedProductMatches {
-        let categories = self.index.repository.categories();
-        let products = self.index.repository.products();
-        let mut by_category = vec![HashMap::new(); categories.len()];
-        let mut has_any_matches = false;
+    fn merge_spans(mut spans: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
+        spans.sort_unstable();
+        let mut merged: Vec<(usize, usize)> = Vec::with_capacity(spans.len());
 
-        for (product_index, product) in products.iter().enumerate() {
-            let matches_search = matched_products.contains(&product_index);
-            let matches_country = country_filter.is_none() || country_filter == product.country;
-            let should_include = matches_search && matches_country;
+        for (start, end) in spans {
+            match merged.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
 
-            for &category_index in product.categories {
-                if let Some(category_map) = by_category.get_mut(category_index) {
-                    category_map.insert(product_index, should_include);
-                    has_any_matches = has_any_matches || should_include;
+        merged
+    }
+
+    fn country_from_words(words: &[&str]) -> Option<Country> {
+        let phrase = words.join(" ");
+        Country::all().iter().copied().find(|country| {
+            country.display_name().eq_ignore_ascii_case(&phrase)
+                || (words.len() == 1 && phrase.chars().all(|character| character.is_ascii_uppercase())
+                    && (country.code().eq_ignore_ascii_case(&phrase) || country.code_alpha3().eq_ignore_ascii_case(&phrase)))
+        })
+    }
+
+    /// Splits country names out of `query`, returning them along with the rest
+    /// of the query. Names match case-insensitively and may span two words,
+    /// while codes only count when typed in uppercase so words like "it" or
+    /// "no" stay text.
+    pub fn detect_countries(query: &str) -> (Vec<(Country, String)>, String) {
+        let words = query.split_whitespace().collect::<Vec<_>>();
+        let mut countries = Vec::new();
+        let mut remaining = Vec::new();
+        let mut position = 0;
+
+        while position < words.len() {
+            let detected = [2, 1].into_iter().find_map(|length| {
+                let window = words.get(position..position + length)?;
+                Self::country_from_words(window).map(|country| (country, length))
+            });
+
+            if let Some((country, length)) = detected {
+                let phrase = words[position..position + length].join(" ");
+                if !countries.iter().any(|(detected, _)| *detected == country) {
+                    countries.push((country, phrase));
                 }
+                position += length;
+            } else {
+                remaining.push(words[position]);
+                position += 1;
             }
         }
 
+        (countries, remaining.join(" "))
+    }
+
+    fn find_matching_products(&self, query: &str, countries: &[Country], mode: SearchMode) -> BTreeSet<usize> {
+        let query_tokens = self.query_tokens(query);
+        let key = (query_tokens.join(" "), countries.to_vec(), mode);
+        let cached_products = self.cache.borrow_mut().get(&key);
+        let matching_products = cached_products.unwrap_or_else(|| {
+            let candidates = self
+                .cache
+                .borrow()
+                .narrowing_candidates(query, &query_tokens, countries, mode)
+                .filter(|_| !self.contains_product_word(&query_tokens));
+            let matching_products = candidates.map_or_else(
+                || self.scan_products(0..self.index.borrow().repository.products().len(), &query_tokens, countries, mode),
+                |candidates| self.scan_products(candidates, &query_tokens, countries, mode),
+            );
+
+            self.cache.borrow_mut().insert(key, matching_products.clone());
+            matching_products
+        });
+
+        self.cache.borrow_mut().last_query = Some(LastQuery {
+            query: query.to_string(),
+            tokens: query_tokens,
+            countries: countries.to_vec(),
+            mode,
+            matches: matching_products.clone(),
+        });
+
+        matching_products
+    }
+
+    fn scan_products<I>(&self, candidates: I, query_tokens: &[String], countries: &[Country], mode: SearchMode) -> BTreeSet<usize>
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let repository = self.index.borrow().repository;
+        let mut matching_products = BTreeSet::new();
+
+        for product_index in candidates {
+            let Some(product) = repository.product_by_index(product_index) else {
+                continue;
+            };
+
+            let matches_country = countries.is_empty()
+                || product.country.is_some_and(|country| countries.contains(&country));
+            if matches_country && self.product_matches_query(product_index, query_tokens, mode) {
+                matching_products.insert(product_index);
+            }
+        }
+
+        matching_products
+    }
+
+    fn categorize_products(&self, matched_products: BTreeSet<usize>) -> CategorizedProductMatches {
+        let repository = self.index.borrow().repository;
+        let total_matches = matched_products.len();
+        let matched_products = Rc::new(matched_products);
+        let by_category = (0..repository.categories().len())
+            .map(|category_index| CategoryMatches {
+                members: repository.category_member_set(category_index),
+                matched_products: matched_products.clone(),
+            })
+            .collect::<Vec<_>>();
+        let has_any_matches = by_category.iter().any(CategoryMatches::has_matches);
+
         CategorizedProductMatches {
             by_category,
-            has_any_matches
+            has_any_matches,
+            total_matches
         }
     }
 
     pub fn new(repository: Repository) -> Self {
-        let product_tokens = Self::build_product_tokens(repository);
-        let index = Rc::new(SearchIndex {
+        Self::with_extended_tokens(repository, false)
+    }
+
+    /// `extended_tokens` enables the digit and camelCase handling of
+    /// `tokenize_with` for both the index and queries.
+    pub fn with_extended_tokens(repository: Repository, extended_tokens: bool) -> Self {
+        let product_tokens = Self::build_product_tokens(repository, extended_tokens);
+        let product_words = Self::collect_product_words(&product_tokens);
+        let index = Rc::new(RefCell::new(SearchIndex {
             repository,
+            extended_tokens,
             product_tokens,
-        });
+            product_words,
+            scorer: Box::new(Self::field_weighted_score),
+        }));
+        let cache = Rc::new(RefCell::new(QueryCache::new(DEFAULT_CACHE_CAPACITY)));
 
-        Self { index }
+        Self { index, cache }
     }
 
-    pub fn find_by_category(&self, query: &str, country_filter: Option<Country>) -> CategorizedProductMatches {
-        let matched_products = if query.trim().is_empty() {
-            let mut all_products = BTreeSet::new();
-            for index in 0..self.index.repository.products().len() {
-                all_products.insert(index);
-            }
-            all_products
-        } else {
-            self.find_matching_products(query)
+    pub fn set_cache_capacity(&self, capacity: usize) {
+        self.cache.borrow_mut().set_capacity(capacity);
+    }
+
+    /// Rebuilds the tokens of a single product. Cost is proportional to the
+    /// size of that product's searchable text, independent of catalog size.
+    #[cfg_attr(not(test), expect(dead_code))]
+    pub fn reindex_product(&self, product_index: usize) -> bool {
+        let mut index = self.index.borrow_mut();
+        let repository = index.repository;
+
+        let Some(product) = repository.product_by_index(product_index) else {
+            return false;
+        };
+
+        let search_text = Self::build_product_search_text(product, repository);
+        let tokens = Self::tokenize_with(&search_text, index.extended_tokens);
+
+        if index.product_tokens.len() <= product_index {
+            index.product_tokens.resize_with(product_index + 1, Tokens::default);
+        }
+
+        index.product_words.extend(tokens.words.iter().cloned());
+        index.product_tokens[product_index] = tokens;
+        self.cache.borrow_mut().clear();
+        true
+    }
+
+    /// Rebuilds the tokens of every product. Cost is linear in the total size
+    /// of the catalog, the same as constructing a new engine.
+    #[cfg_attr(not(test), expect(dead_code))]
+    pub fn reindex_all(&self) {
+        let mut index = self.index.borrow_mut();
+        let repository = index.repository;
+        index.product_tokens = Self::build_product_tokens(repository, index.extended_tokens);
+        index.product_words = Self::collect_product_words(&index.product_tokens);
+        self.cache.borrow_mut().clear();
+    }
+
+    /// Replaces the relevance function used by `find_ranked`. The scorer runs
+    /// once per candidate product with the tokenized query, so it should be cheap.
+    #[cfg_attr(not(test), expect(dead_code))]
+    pub fn set_scorer(&self, scorer: Scorer) {
+        self.index.borrow_mut().scorer = scorer;
+    }
+
+    pub fn global_search(&self, query: &str) -> GlobalResults {
+        let query_tokens = self.query_tokens(query);
+        if query_tokens.is_empty() {
+            return GlobalResults::default();
+        }
+
+        let repository = self.index.borrow().repository;
+        let matches_all_tokens = |text: &str| {
+            let normalized = Self::normalize_text(text);
+            query_tokens.iter().all(|token| normalized.contains(token.as_str()))
+        };
+
+        let categories = repository
+            .categories_sorted()
+            .into_iter()
+            .filter(|(_, category)| matches_all_tokens(category.name))
+            .map(|(category_index, _)| category_index)
+            .collect();
+
+        let countries = Country::parse(query.trim()).map_or_else(
+            || {
+                let mut countries = Country::all()
+                    .iter()
+                    .copied()
+                    .filter(|country| matches_all_tokens(country.display_name()))
+                    .collect::<Vec<_>>();
+                let region_countries = Region::all()
+                    .iter()
+                    .copied()
+                    .filter(|region| matches_all_tokens(region.display_name()))
+                    .flat_map(Country::in_region);
+                for country in region_countries {
+                    if !countries.contains(&country) {
+                        countries.push(country);
+                    }
+                }
+                countries
+            },
+            |country| vec![country],
+        );
+
+        GlobalResults {
+            products: self.find_ranked(query),
+            categories,
+            countries,
+        }
+    }
+
+    /// Byte ranges of the product's name where query tokens occur, sorted and
+    /// with overlapping ranges merged, for highlighting.
+    pub fn match_spans(&self, product_index: usize, query: &str) -> Vec<(usize, usize)> {
+        let repository = self.index.borrow().repository;
+        let Some(product) = repository.product_by_index(product_index) else {
+            return Vec::new();
         };
 
-        self.categorize_products(&matched_products, country_filter)
+        let name = product.name;
+        let mut spans = Vec::new();
+        for token in self.query_tokens(query) {
+            for (start, _) in name.char_indices() {
+                if let Some(end) = Self::case_insensitive_match_end(name, start, &token) {
+                    spans.push((start, end));
+                }
+            }
+        }
+
+        Self::merge_spans(spans)
+    }
+
+    /// Product name as Pango markup with every matched span wrapped in `<b>`.
+    /// Text outside and inside the spans is escaped.
+    pub fn highlighted_name(&self, product_index: usize, query: &str) -> Option<String> {
+        let repository = self.index.borrow().repository;
+        let name = repository.product_by_index(product_index)?.name;
+        let mut markup = String::with_capacity(name.len());
+        let mut position = 0;
+
+        for (start, end) in self.match_spans(product_index, query) {
+            markup.push_str(&glib::markup_escape_text(&name[position..start]));
+            markup.push_str("<b>");
+            markup.push_str(&glib::markup_escape_text(&name[start..end]));
+            markup.push_str("</b>");
+            position = end;
+        }
+
+        markup.push_str(&glib::markup_escape_text(&name[position..]));
+        Some(markup)
+    }
+
+    pub fn find_ranked(&self, query: &str) -> Vec<usize> {
+        let matched_products = self.find_matching_products(query, &[], SearchMode::All);
+        let query_tokens = self.query_tokens(query);
+        let index = self.index.borrow();
+        let repository = index.repository;
+
+        let mut scored_products = matched_products
+            .into_iter()
+            .filter_map(|product_index| {
+                repository
+                    .product_by_index(product_index)
+                    .map(|product| (product_index, (index.scorer)(product, &query_tokens)))
+            })
+            .collect::<Vec<_>>();
+
+        scored_products.sort_by(|(_, score_a), (_, score_b)| score_b.total_cmp(score_a));
+        scored_products.into_iter().map(|(product_index, _)| product_index).collect()
+    }
+
+    /// Like `find_by_category_multi`, but country names in the query act as a
+    /// country filter: a product matches one when it is from that country or
+    /// mentions it in its text. The other words are matched as usual.
+    pub fn find_by_category_auto_country(&self, query: &str, countries: &[Country], mode: SearchMode) -> CategorizedProductMatches {
+        let (detected_countries, remaining_query) = Self::detect_countries(query);
+        if detected_countries.is_empty() {
+            return self.find_by_category_multi(query, countries, mode);
+        }
+
+        let repository = self.index.borrow().repository;
+        let country_tokens = detected_countries
+            .iter()
+            .map(|(_, phrase)| self.query_tokens(phrase))
+            .collect::<Vec<_>>();
+        let matched_products = self
+            .find_matching_products(&remaining_query, countries, mode)
+            .into_iter()
+            .filter(|&product_index| {
+                let from_country = repository
+                    .product_by_index(product_index)
+                    .and_then(|product| product.country)
+                    .is_some_and(|country| detected_countries.iter().any(|(detected, _)| *detected == country));
+                from_country || country_tokens.iter().any(|tokens| self.product_mentions(product_index, tokens))
+            })
+            .collect();
+
+        self.categorize_products(matched_products)
+    }
+
+    pub fn find_by_category_multi(&self, query: &str, countries: &[Country], mode: SearchMode) -> CategorizedProductMatches {
+        let matched_products = self.find_matching_products(query, countries, mode);
+        self.categorize_products(matched_products)
+    }
+
+    #[cfg_attr(not(test), expect(dead_code))]
+    pub fn find_by_category(&self, query: &str, country_filter: Option<Country>) -> CategorizedProductMatches {
+        self.find_by_category_with_mode(query, country_filter, SearchMode::All)
+    }
+
+    #[cfg_attr(not(test), expect(dead_code))]
+    pub fn find_by_category_with_mode(
+        &self,
+        query: &str,
+        country_filter: Option<Country>,
+        mode: SearchMode
+    ) -> CategorizedProductMatches {
+        self.find_by_category_multi(query, country_filter.as_slice(), mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_catalog;
+    use std::collections::HashMap;
+    use std::time::Instant;
+
+    fn category_map(matches: &CategoryMatches) -> HashMap<usize, bool> {
+        matches
+            .members
+            .into_iter()
+            .flatten()
+            .map(|&product_index| (product_index, matches.is_included(product_index)))
+            .collect()
+    }
+
+    /// Compares categorizing with the shared member sets against building a
+    /// map per category, as every search used to. Run with
+    /// `cargo test --release categorizing_benchmark -- --ignored --nocapture`.
+    #[test]
+    #[ignore = "benchmark"]
+    fn categorizing_benchmark() {
+        const ITERATIONS: u32 = 100_000;
+        let search_engine = SearchEngine::new(test_catalog::repository());
+        let matched_products = BTreeSet::from([0, 2]);
+
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            let by_category = test_catalog::CATALOG
+                .category_products
+                .iter()
+                .map(|product_indices| {
+                    product_indices
+                        .iter()
+                        .map(|&product_index| (product_index, matched_products.contains(&product_index)))
+                        .collect::<HashMap<_, _>>()
+                })
+                .collect::<Vec<_>>();
+            std::hint::black_box(by_category);
+        }
+        let per_category_maps = start.elapsed();
+
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            std::hint::black_box(search_engine.categorize_products(matched_products.clone()));
+        }
+        let shared_member_sets = start.elapsed();
+
+        println!("per-category maps: {per_category_maps:?}, shared member sets: {shared_member_sets:?}");
+    }
+
+    fn matches(query: &str) -> BTreeSet<usize> {
+        SearchEngine::new(test_catalog::repository()).find_matching_products(query, &[], SearchMode::All)
+    }
+
+    #[test]
+    fn categorizes_matches_from_its_own_catalog() {
+        let search_engine = SearchEngine::new(test_catalog::repository());
+        let matches = search_engine.categorize_products(BTreeSet::from([2]));
+
+        assert_eq!(matches.by_category.len(), 4);
+        assert_eq!(category_map(&matches.by_category[0]), HashMap::from([(1, false)]));
+        assert_eq!(category_map(&matches.by_category[1]), HashMap::from([(0, false), (2, true)]));
+        assert_eq!(category_map(&matches.by_category[2]), HashMap::from([(2, true)]));
+        assert!(matches.has_any_matches);
+        assert_eq!(matches.total_matches, 1);
+    }
+
+    fn matches_in_mode(query: &str, mode: SearchMode) -> BTreeSet<usize> {
+        SearchEngine::new(test_catalog::repository())
+            .find_by_category_with_mode(query, None, mode)
+            .by_category
+            .iter()
+            .flat_map(category_map)
+            .filter_map(|(product_index, included)| included.then_some(product_index))
+            .collect()
+    }
+
+    #[test]
+    fn all_mode_needs_every_word() {
+        assert_eq!(matches_in_mode("proton nextcloud", SearchMode::All), BTreeSet::new());
+        assert_eq!(matches_in_mode("browser mail", SearchMode::All), BTreeSet::from([2]));
+    }
+
+    #[test]
+    fn any_mode_needs_one_word() {
+        assert_eq!(matches_in_mode("proton nextcloud", SearchMode::Any), BTreeSet::from([0, 1]));
+        assert_eq!(matches_in_mode("proton unknown", SearchMode::Any), BTreeSet::from([0]));
+    }
+
+    #[test]
+    fn empty_queries_match_everything_in_both_modes() {
+        for mode in [SearchMode::All, SearchMode::Any] {
+            assert_eq!(matches_in_mode("", mode), BTreeSet::from([0, 1, 2, 3]));
+            assert_eq!(matches_in_mode("  ", mode), BTreeSet::from([0, 1, 2, 3]));
+        }
+    }
+
+    #[test]
+    fn modes_are_cached_separately() {
+        let search_engine = SearchEngine::new(test_catalog::repository());
+
+        assert_eq!(search_engine.find_matching_products("proton nextcloud", &[], SearchMode::All), BTreeSet::new());
+        assert_eq!(
+            search_engine.find_matching_products("proton nextcloud", &[], SearchMode::Any),
+            BTreeSet::from([0, 1])
+        );
     }
-}
\ No newline at end of file
+
+    fn matches_in_countries(query: &str, countries: &[Country]) -> BTreeSet<usize> {
+        SearchEngine::new(test_catalog::repository()).find_matching_products(query, countries, SearchMode::All)
+    }
+
+    #[test]
+    fn two_country_filters_include_products_from_either_country() {
+        let countries = [Country::Germany, Country::Switzerland];
+
+        assert_eq!(matches_in_countries("", &countries), BTreeSet::from([0, 1]));
+        assert_eq!(matches_in_countries("cloud", &countries), BTreeSet::from([1]));
+        assert_eq!(matches_in_countries("mail", &countries), BTreeSet::from([0]));
+    }
+
+    #[test]
+    fn two_country_filters_exclude_other_and_unknown_countries() {
+        let countries = [Country::Germany, Country::CzechRepublic];
+
+        assert_eq!(matches_in_countries("", &countries), BTreeSet::from([1, 3]));
+        assert_eq!(matches_in_countries("mail", &countries), BTreeSet::new());
+        assert_eq!(matches_in_countries("", &[Country::Sweden, Country::France]), BTreeSet::new());
+    }
+
+    #[test]
+    fn multi_country_results_are_categorized() {
+        let search_engine = SearchEngine::new(test_catalog::repository());
+        let matches = search_engine.find_by_category_multi("", &[Country::Germany, Country::Switzerland], SearchMode::All);
+
+        assert_eq!(matches.total_matches, 2);
+        assert_eq!(category_map(&matches.by_category[0]), HashMap::from([(1, true)]));
+        assert_eq!(category_map(&matches.by_category[1]), HashMap::from([(0, true), (2, false)]));
+        assert_eq!(category_map(&matches.by_category[3]), HashMap::from([(3, false)]));
+    }
+
+    #[test]
+    fn single_country_filter_is_optional() {
+        let search_engine = SearchEngine::new(test_catalog::repository());
+
+        assert_eq!(search_engine.find_by_category("", None).total_matches, 4);
+        let matches = search_engine.find_by_category("", Some(Country::Germany));
+        assert_eq!(matches.total_matches, 1);
+        assert_eq!(category_map(&matches.by_category[0]), HashMap::from([(1, true)]));
+    }
+
+    #[test]
+    fn region_names_find_their_countries() {
+        let search_engine = SearchEngine::new(test_catalog::repository());
+
+        assert_eq!(
+            search_engine.global_search("nordic").countries,
+            [Country::Denmark, Country::Finland, Country::Sweden]
+        );
+        assert_eq!(
+            search_engine.global_search("Baltics").countries,
+            [Country::Estonia, Country::Latvia, Country::Lithuania]
+        );
+    }
+
+    #[test]
+    fn countries_of_several_regions_are_listed_once() {
+        let search_engine = SearchEngine::new(test_catalog::repository());
+        let countries = search_engine.global_search("europe").countries;
+
+        assert_eq!(countries.len(), Country::COUNT - 6);
+        assert!(countries.iter().all(|country| !matches!(country.region(), Region::Nordic | Region::Baltic)));
+        assert_eq!(search_engine.global_search("central europe").countries, Country::in_region(Region::Central));
+    }
+
+    #[test]
+    fn match_spans_cover_every_query_word() {
+        let search_engine = SearchEngine::new(test_catalog::repository());
+
+        assert_eq!(search_engine.match_spans(0, "proton mail"), [(0, 6), (7, 11)]);
+        assert_eq!(search_engine.match_spans(0, "MAIL"), [(7, 11)]);
+        assert_eq!(search_engine.match_spans(3, "photo"), [(0, 5)]);
+    }
+
+    #[test]
+    fn overlapping_match_spans_are_merged() {
+        let search_engine = SearchEngine::new(test_catalog::repository());
+
+        assert_eq!(search_engine.match_spans(0, "mail ail"), [(7, 11)]);
+        assert_eq!(search_engine.match_spans(3, "photo otope"), [(0, 7)]);
+        assert_eq!(SearchEngine::merge_spans(vec![(5, 8), (0, 2), (1, 3), (8, 9)]), [(0, 3), (5, 9)]);
+    }
+
+    #[test]
+    fn match_spans_are_empty_without_matches() {
+        let search_engine = SearchEngine::new(test_catalog::repository());
+
+        assert!(search_engine.match_spans(1, "mail").is_empty());
+        assert!(search_engine.match_spans(0, "").is_empty());
+        assert!(search_engine.match_spans(42, "mail").is_empty());
+    }
+
+    #[test]
+    fn highlighted_names_bold_the_spans() {
+        let search_engine = SearchEngine::new(test_catalog::repository());
+
+        assert_eq!(search_engine.highlighted_name(0, "mail").as_deref(), Some("Proton <b>Mail</b>"));
+        assert_eq!(search_engine.highlighted_name(2, "").as_deref(), Some("Vivaldi"));
+        assert_eq!(search_engine.highlighted_name(42, "mail"), None);
+    }
+
+    #[test]
+    fn country_names_are_split_out_of_queries() {
+        assert_eq!(
+            SearchEngine::detect_countries("germany signal"),
+            (vec![(Country::Germany, String::from("germany"))], String::from("signal"))
+        );
+        assert_eq!(
+            SearchEngine::detect_countries("vpn United Kingdom"),
+            (vec![(Country::UnitedKingdom, String::from("United Kingdom"))], String::from("vpn"))
+        );
+        assert_eq!(
+            SearchEngine::detect_countries("Germany mail germany"),
+            (vec![(Country::Germany, String::from("Germany"))], String::from("mail"))
+        );
+    }
+
+    #[test]
+    fn country_codes_only_count_in_uppercase() {
+        assert_eq!(
+            SearchEngine::detect_countries("DE mail"),
+            (vec![(Country::Germany, String::from("DE"))], String::from("mail"))
+        );
+        assert_eq!(SearchEngine::detect_countries("it editor"), (Vec::new(), String::from("it editor")));
+        assert_eq!(SearchEngine::detect_countries("no mail"), (Vec::new(), String::from("no mail")));
+    }
+
+    fn auto_country_matches(query: &str) -> usize {
+        SearchEngine::new(test_catalog::repository())
+            .find_by_category_auto_country(query, &[], SearchMode::All)
+            .total_matches
+    }
+
+    #[test]
+    fn country_names_narrow_the_results() {
+        let search_engine = SearchEngine::new(test_catalog::repository());
+        let matches = search_engine.find_by_category_auto_country("germany cloud", &[], SearchMode::All);
+
+        assert_eq!(matches.total_matches, 1);
+        assert_eq!(category_map(&matches.by_category[0]), HashMap::from([(1, true)]));
+        assert_eq!(auto_country_matches("germany mail"), 0);
+        assert_eq!(auto_country_matches("Czech Republic"), 1);
+    }
+
+    #[test]
+    fn country_names_are_not_found_inside_other_words() {
+        // Vivaldi's text has "and", which "switzerland" contains.
+        assert_eq!(auto_country_matches("switzerland mail"), 1);
+        assert_eq!(auto_country_matches("switzerland browser"), 0);
+    }
+
+    #[test]
+    fn country_names_combine_with_the_country_filter() {
+        let search_engine = SearchEngine::new(test_catalog::repository());
+
+        let matches = search_engine.find_by_category_auto_country("germany", &[Country::Germany], SearchMode::All);
+        assert_eq!(matches.total_matches, 1);
+
+        let matches = search_engine.find_by_category_auto_country("germany", &[Country::Switzerland], SearchMode::All);
+        assert_eq!(matches.total_matches, 0);
+    }
+
+    #[test]
+    fn extended_tokens_keep_short_words_with_digits() {
+        let tokens = SearchEngine::tokenize_with("S3 and o3 go", true);
+        assert_eq!(tokens.words, ["s3", "and", "o3"]);
+
+        let tokens = SearchEngine::tokenize_with("S3 and o3 go", false);
+        assert_eq!(tokens.words, ["and"]);
+    }
+
+    #[test]
+    fn extended_tokens_split_camel_case_and_digits() {
+        let tokens = SearchEngine::tokenize_with("NextCloud web3", true);
+        assert_eq!(tokens.words, ["nextcloud", "web3"]);
+        assert_eq!(tokens.sub_tokens, ["next", "cloud", "web"]);
+
+        assert!(SearchEngine::tokenize_with("NextCloud web3", false).sub_tokens.is_empty());
+        assert_eq!(SearchEngine::split_word_boundaries("macOS14"), ["mac", "os", "14"]);
+    }
+
+    #[test]
+    fn short_numeric_queries_filter_only_with_extended_tokens() {
+        let repository = test_catalog::repository();
+
+        assert!(SearchEngine::with_extended_tokens(repository, true).find_ranked("s3").is_empty());
+        assert_eq!(SearchEngine::new(repository).find_ranked("s3").len(), 4);
+    }
+
+    fn cache_key(query: &str) -> QueryKey {
+        (query.to_string(), Vec::new(), SearchMode::All)
+    }
+
+    #[test]
+    fn cached_results_equal_uncached_results() {
+        let search_engine = SearchEngine::new(test_catalog::repository());
+
+        for query in ["mail", "cloud", "photo", "browser mail", "nothing"] {
+            let uncached = search_engine.find_matching_products(query, &[], SearchMode::All);
+            let cached = search_engine.find_matching_products(query, &[], SearchMode::All);
+
+            assert_eq!(cached, uncached, "query {query:?}");
+            assert_eq!(cached, matches(query), "query {query:?}");
+        }
+    }
+
+    #[test]
+    fn cache_evicts_the_least_recently_used_query() {
+        let mut cache = QueryCache::new(2);
+        cache.insert(cache_key("a"), BTreeSet::from([0]));
+        cache.insert(cache_key("b"), BTreeSet::from([1]));
+
+        assert_eq!(cache.get(&cache_key("a")), Some(BTreeSet::from([0])));
+
+        cache.insert(cache_key("c"), BTreeSet::from([2]));
+
+        assert_eq!(cache.get(&cache_key("b")), None);
+        assert_eq!(cache.get(&cache_key("a")), Some(BTreeSet::from([0])));
+        assert_eq!(cache.get(&cache_key("c")), Some(BTreeSet::from([2])));
+    }
+
+    #[test]
+    fn cache_without_capacity_stores_nothing() {
+        let mut cache = QueryCache::new(0);
+        cache.insert(cache_key("a"), BTreeSet::from([0]));
+
+        assert_eq!(cache.get(&cache_key("a")), None);
+    }
+
+    #[test]
+    fn shrinking_the_cache_drops_the_oldest_queries() {
+        let mut cache = QueryCache::new(3);
+        for (matches, query) in ["a", "b", "c"].into_iter().enumerate() {
+            cache.insert(cache_key(query), BTreeSet::from([matches]));
+        }
+
+        cache.set_capacity(1);
+
+        assert_eq!(cache.get(&cache_key("a")), None);
+        assert_eq!(cache.get(&cache_key("b")), None);
+        assert_eq!(cache.get(&cache_key("c")), Some(BTreeSet::from([2])));
+    }
+
+    fn last_query(query: &str) -> LastQuery {
+        LastQuery {
+            query: query.to_string(),
+            tokens: SearchEngine::tokenize_with(query, false).into_query_tokens(),
+            countries: Vec::new(),
+            mode: SearchMode::All,
+            matches: BTreeSet::new(),
+        }
+    }
+
+    fn is_narrowed(previous: &str, query: &str) -> bool {
+        let tokens = SearchEngine::tokenize_with(query, false).into_query_tokens();
+        last_query(previous).is_narrowed_by(query, &tokens, &[], SearchMode::All)
+    }
+
+    #[test]
+    fn extending_a_word_narrows_the_query() {
+        assert!(is_narrowed("sig", "sign"));
+        assert!(is_narrowed("sign", "sign-on"));
+    }
+
+    #[test]
+    fn adding_a_word_narrows_the_query() {
+        assert!(is_narrowed("proton", "proton mail"));
+        assert!(is_narrowed("web", "web browser"));
+    }
+
+    #[test]
+    fn unrelated_queries_are_not_narrowed() {
+        assert!(!is_narrowed("sign", "sig"));
+        assert!(!is_narrowed("mail", "email"));
+    }
+
+    #[test]
+    fn narrowed_results_equal_a_full_scan() {
+        for (previous, query) in [("sig", "sign"), ("mai", "mail"), ("web", "web browser"), ("cloud", "cloud all-in-one"), ("web", "webmail")] {
+            let search_engine = SearchEngine::new(test_catalog::repository());
+            search_engine.find_matching_products(previous, &[], SearchMode::All);
+
+            let narrowed = search_engine.find_matching_products(query, &[], SearchMode::All);
+            let full_scan = SearchEngine::new(test_catalog::repository())
+                .find_matching_products(query, &[], SearchMode::All);
+            assert_eq!(narrowed, full_scan, "{previous} → {query}");
+        }
+    }
+
+    #[test]
+    fn tokens_containing_a_product_word_are_not_narrowed() {
+        let search_engine = SearchEngine::new(test_catalog::repository());
+
+        assert!(search_engine.contains_product_word(&tokens("webmail")));
+        assert!(!search_engine.contains_product_word(&tokens("sign")));
+    }
+
+    fn tokens(query: &str) -> Vec<String> {
+        SearchEngine::tokenize_with(query, false).into_query_tokens()
+    }
+
+    #[test]
+    fn reindexing_one_product_picks_up_changed_tokens() {
+        let search_engine = SearchEngine::new(test_catalog::repository());
+        search_engine.index.borrow_mut().product_tokens[3] = Tokens::default();
+        assert!(search_engine.find_matching_products("photo", &[], SearchMode::All).is_empty());
+
+        assert!(search_engine.reindex_product(3));
+        assert_eq!(search_engine.find_matching_products("photo", &[], SearchMode::All), BTreeSet::from([3]));
+        assert!(!search_engine.reindex_product(42));
+    }
+
+    #[test]
+    fn reindexing_everything_rebuilds_every_product() {
+        let search_engine = SearchEngine::new(test_catalog::repository());
+        search_engine.index.borrow_mut().product_tokens.clear();
+        assert!(search_engine.find_matching_products("mail", &[], SearchMode::All).is_empty());
+
+        search_engine.reindex_all();
+        assert_eq!(search_engine.find_matching_products("mail", &[], SearchMode::All), matches("mail"));
+    }
+
+    #[test]
+    fn name_matches_outrank_description_matches() {
+        let repository = test_catalog::repository();
+        let proton_mail = repository.product_by_index(0).unwrap();
+        let vivaldi = repository.product_by_index(2).unwrap();
+
+        let query_tokens = tokens("mail");
+        assert!(
+            SearchEngine::field_weighted_score(proton_mail, &query_tokens)
+                > SearchEngine::field_weighted_score(vivaldi, &query_tokens)
+        );
+        assert_eq!(SearchEngine::new(repository).find_ranked("mail"), [0, 2]);
+    }
+
+    fn product(name: &'static str, description: &'static str) -> Product {
+        Product {
+            categories: &[],
+            logo: None,
+            name,
+            slug: "",
+            description,
+            summary: "",
+            country: None,
+            websites: &[],
+        }
+    }
+
+    #[test]
+    fn name_prefixes_outrank_substrings_and_descriptions() {
+        let query_tokens = tokens("sig");
+        let name_prefix = SearchEngine::field_weighted_score(&product("Signal", "Private messenger."), &query_tokens);
+        let name_substring = SearchEngine::field_weighted_score(&product("Consignment", "Parcel tracking."), &query_tokens);
+        let description_only = SearchEngine::field_weighted_score(&product("Docs", "Add a signature."), &query_tokens);
+
+        assert!(name_prefix > name_substring);
+        assert!(name_substring > description_only);
+        assert!(description_only > 0.0);
+    }
+
+    #[test]
+    fn full_name_prefixes_outrank_word_prefixes() {
+        let query_tokens = tokens("proton mail");
+
+        assert!(
+            SearchEngine::name_prefix_bonus("proton mail", &query_tokens)
+                > SearchEngine::name_prefix_bonus("mail by proton", &query_tokens)
+        );
+        assert!(SearchEngine::name_prefix_bonus("mail by proton", &query_tokens) > 0.0);
+        assert!(SearchEngine::name_prefix_bonus("webmail", &tokens("mail")).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn injected_scorers_decide_the_ranking() {
+        let search_engine = SearchEngine::new(test_catalog::repository());
+        #[allow(clippy::cast_precision_loss)]
+        search_engine.set_scorer(Box::new(|product, _| -(product.name.len() as f32)));
+
+        // Shortest names first: Vivaldi, Photopea, Nextcloud, Proton Mail.
+        assert_eq!(search_engine.find_ranked(""), [2, 3, 1, 0]);
+        assert_eq!(search_engine.find_ranked("mail"), [2, 0]);
+    }
+
+    #[test]
+    fn unmatched_fields_score_nothing() {
+        let repository = test_catalog::repository();
+        let photopea = repository.product_by_index(3).unwrap();
+
+        assert!(SearchEngine::field_weighted_score(photopea, &tokens("mail")).abs() < f32::EPSILON);
+        assert!(SearchEngine::field_weighted_score(photopea, &[]).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn punctuation_sub_tokens_need_two_characters() {
+        let tokens = SearchEngine::tokenize_with("end-to-end all-in-one node.js e-mail — free", false);
+
+        assert_eq!(tokens.words, ["endtoend", "allinone", "nodejs", "email", "free"]);
+        assert_eq!(tokens.sub_tokens, ["end", "to", "all", "in", "one", "node", "js", "mail"]);
+    }
+
+    #[test]
+    fn boundary_sub_tokens_need_three_characters() {
+        assert_eq!(SearchEngine::tokenize_with("macOS14", true).sub_tokens, ["mac"]);
+    }
+
+    #[test]
+    fn punctuated_words_match_their_parts() {
+        let tokens = SearchEngine::tokenize_with("node.js", false);
+        assert!(SearchEngine::token_matches_product("js", &tokens));
+        assert!(SearchEngine::token_matches_product("node", &tokens));
+
+        let tokens = SearchEngine::tokenize_with("C/C++ IDE", false);
+        assert_eq!(tokens.words, ["c/c++", "ide"]);
+        assert!(SearchEngine::token_matches_product("c++", &tokens));
+        assert_eq!(SearchEngine::tokenize_with("c++", false).into_query_tokens(), ["c++"]);
+    }
+
+    #[test]
+    fn sub_tokens_do_not_match_inside_longer_queries() {
+        assert_eq!(matches("photo"), BTreeSet::from([3]));
+        assert!(matches("linux").is_empty());
+        assert!(matches("signin").is_empty());
+    }
+
+    #[test]
+    fn sub_tokens_match_query_prefixes() {
+        let tokens = SearchEngine::tokenize_with("node.js", false);
+
+        assert!(SearchEngine::token_matches_product("nod", &tokens));
+        assert!(SearchEngine::token_matches_product("node", &tokens));
+        assert!(!SearchEngine::token_matches_product("nodes", &tokens));
+        assert_eq!(matches("sign-on"), BTreeSet::from([0]));
+    }
+}
@@ -0,0 +1,181 @@
+use super::prelude::*;
+use super::constants;
+
+use std::path::PathBuf;
+use std::rc::Rc;
+
+const KEY_FILE_GROUP: &str = "settings";
+const KEY_FILE_NAME: &str = "settings.ini";
+
+pub struct KeyFileStore {
+    key_file: glib::KeyFile,
+    path: PathBuf,
+}
+
+impl KeyFileStore {
+    fn open(path: PathBuf) -> Self {
+        let key_file = glib::KeyFile::new();
+        // A missing or unreadable file just means every key falls back to its default.
+        let _ = key_file.load_from_file(&path, glib::KeyFileFlags::KEEP_COMMENTS);
+        Self { key_file, path }
+    }
+
+    fn save(&self) -> Result<()> {
+        if let Some(directory) = self.path.parent() {
+            std::fs::create_dir_all(directory)?;
+        }
+        self.key_file.save_to_file(&self.path)?;
+        Ok(())
+    }
+}
+
+/// Application settings backed by GSettings when the schema is installed and by
+/// a key file in the user config directory otherwise.
+#[derive(Clone)]
+pub enum SettingsStore {
+    Schema(gtk::gio::Settings),
+    KeyFile(Rc<KeyFileStore>),
+}
+
+impl Default for SettingsStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SettingsStore {
+
+    pub fn new() -> Self {
+        #[cfg(schemas_installed)]
+        {
+            Self::Schema(gtk::gio::Settings::new(constants::APP_ID))
+        }
+        #[cfg(not(schemas_installed))]
+        {
+            Self::with_key_file(Self::default_key_file_path())
+        }
+    }
+
+    pub fn with_key_file(path: PathBuf) -> Self {
+        Self::KeyFile(Rc::new(KeyFileStore::open(path)))
+    }
+
+    pub fn default_key_file_path() -> PathBuf {
+        glib::user_config_dir()
+            .join(constants::APP_NAME)
+            .join(KEY_FILE_NAME)
+    }
+
+    pub fn int(&self, key: &str, default: i32) -> i32 {
+        match self {
+            Self::Schema(settings) => settings.int(key),
+            Self::KeyFile(store) => store.key_file.integer(KEY_FILE_GROUP, key).unwrap_or(default),
+        }
+    }
+
+    pub fn set_int(&self, key: &str, value: i32) -> Result<()> {
+        match self {
+            Self::Schema(settings) => Ok(settings.set_int(key, value)?),
+            Self::KeyFile(store) => {
+                store.key_file.set_integer(KEY_FILE_GROUP, key, value);
+                store.save()
+            }
+        }
+    }
+
+    pub fn boolean(&self, key: &str, default: bool) -> bool {
+        match self {
+            Self::Schema(settings) => settings.boolean(key),
+            Self::KeyFile(store) => store.key_file.boolean(KEY_FILE_GROUP, key).unwrap_or(default),
+        }
+    }
+
+    pub fn set_boolean(&self, key: &str, value: bool) -> Result<()> {
+        match self {
+            Self::Schema(settings) => Ok(settings.set_boolean(key, value)?),
+            Self::KeyFile(store) => {
+                store.key_file.set_boolean(KEY_FILE_GROUP, key, value);
+                store.save()
+            }
+        }
+    }
+
+    pub fn string(&self, key: &str, default: &str) -> String {
+        match self {
+            Self::Schema(settings) => settings.string(key).to_string(),
+            Self::KeyFile(store) => store.key_file
+                .string(KEY_FILE_GROUP, key)
+                .map_or_else(|_| default.to_string(), |value| value.to_string()),
+        }
+    }
+
+    pub fn set_string(&self, key: &str, value: &str) -> Result<()> {
+        match self {
+            Self::Schema(settings) => Ok(settings.set_string(key, value)?),
+            Self::KeyFile(store) => {
+                store.key_file.set_string(KEY_FILE_GROUP, key, value);
+                store.save()
+            }
+        }
+    }
+
+    pub fn strv(&self, key: &str) -> Vec<String> {
+        match self {
+            Self::Schema(settings) => settings.strv(key).iter().map(ToString::to_string).collect(),
+            Self::KeyFile(store) => store.key_file
+                .string_list(KEY_FILE_GROUP, key)
+                .map(|values| values.iter().map(ToString::to_string).collect())
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn set_strv(&self, key: &str, values: &[&str]) -> Result<()> {
+        match self {
+            Self::Schema(settings) => Ok(settings.set_strv(key, values)?),
+            Self::KeyFile(store) => {
+                store.key_file.set_string_list(KEY_FILE_GROUP, key, values);
+                store.save()
+            }
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key_file_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("{app}-{name}-{pid}", app = constants::APP_NAME, pid = std::process::id()))
+            .join(KEY_FILE_NAME)
+    }
+
+    #[test]
+    fn key_file_values_survive_reopening() {
+        let path = key_file_path("round-trip");
+        let _ = std::fs::remove_file(&path);
+
+        let store = SettingsStore::with_key_file(path.clone());
+        store.set_int("width", 800).unwrap();
+        store.set_boolean("maximized", true).unwrap();
+        store.set_string("category-sort", "name").unwrap();
+        store.set_strv("favorites", &["proton-mail", "nextcloud"]).unwrap();
+
+        let reopened = SettingsStore::with_key_file(path.clone());
+        assert_eq!(reopened.int("width", 0), 800);
+        assert!(reopened.boolean("maximized", false));
+        assert_eq!(reopened.string("category-sort", "slug"), "name");
+        assert_eq!(reopened.strv("favorites"), ["proton-mail", "nextcloud"]);
+
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn missing_key_file_values_fall_back_to_defaults() {
+        let store = SettingsStore::with_key_file(key_file_path("missing"));
+
+        assert_eq!(store.int("width", 1000), 1000);
+        assert!(store.boolean("maximized", true));
+        assert_eq!(store.string("category-sort", "slug"), "slug");
+        assert!(store.strv("favorites").is_empty());
+    }
+}
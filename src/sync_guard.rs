@@ -0,0 +1,58 @@
+use std::cell::Cell;
+
+/// Breaks feedback loops between two widgets kept in sync with each other,
+/// where updating one side emits the signal that would update the other.
+#[derive(Debug, Default)]
+pub struct SyncGuard {
+    active: Cell<bool>,
+}
+
+impl SyncGuard {
+    /// Runs `action` unless the guard is already held, which is the case when
+    /// `action` ends up calling back into `run` on the same guard. Returns
+    /// whether `action` ran.
+    pub fn run<F>(&self, action: F) -> bool
+    where
+        F: FnOnce(),
+    {
+        if self.active.replace(true) {
+            return false;
+        }
+
+        action();
+        self.active.set(false);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_run_is_skipped() {
+        let guard = SyncGuard::default();
+        let nested_ran = Cell::new(false);
+        let mut nested_result = true;
+
+        let outer_result = guard.run(|| {
+            nested_result = guard.run(|| nested_ran.set(true));
+        });
+
+        assert!(outer_result);
+        assert!(!nested_result);
+        assert!(!nested_ran.get());
+    }
+
+    #[test]
+    fn guard_is_released_after_running() {
+        let guard = SyncGuard::default();
+        guard.run(|| {
+            guard.run(|| ());
+        });
+
+        let ran = Cell::new(false);
+        assert!(guard.run(|| ran.set(true)));
+        assert!(ran.get());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,137 @@
+use crate::constants::APP_SOURCE_NAME;
+use crate::models::{Catalog, Category, Country, Product};
+use crate::repository::Repository;
+
+use std::sync::OnceLock;
+
+const NO_PRODUCTS: &[usize] = &[];
+
+const COUNTRY_PRODUCTS: [&[usize]; Country::COUNT] = {
+    let mut country_products = [NO_PRODUCTS; Country::COUNT];
+    country_products[Country::Switzerland as usize] = &[0];
+    country_products[Country::Germany as usize] = &[1];
+    country_products[Country::CzechRepublic as usize] = &[3];
+    country_products
+};
+
+/// Small catalog for tests, independent of the one scraped at build time.
+pub static CATALOG: Catalog = Catalog {
+    version: Catalog::VERSION,
+    categories: &[
+        Category {
+            slug: "cloud-storage",
+            name: "Cloud Storage",
+            description: "Store and share files online.",
+            summary: "Store and share files online.",
+            icon: "cloud-storage",
+        },
+        Category {
+            slug: "email-providers",
+            name: "Email Providers",
+            description: "Send and receive email.",
+            summary: "Send and receive email.",
+            icon: "email-providers",
+        },
+        Category {
+            slug: "browsers",
+            name: "Browsers",
+            description: "Browse the web.",
+            summary: "Browse the web.",
+            icon: "browsers",
+        },
+        Category {
+            slug: "image-editors",
+            name: "Image Editors",
+            description: "Edit pictures.",
+            summary: "Edit pictures.",
+            icon: "image-editors",
+        },
+    ],
+    products: &[
+        Product {
+            categories: &[1],
+            logo: None,
+            name: "Proton Mail",
+            slug: "proton-mail",
+            description: "End-to-end encrypted email with single sign-on.",
+            summary: "End-to-end encrypted email.",
+            country: Some(Country::Switzerland),
+            websites: &[
+                ("Website", "https://proton.me/mail"),
+                (APP_SOURCE_NAME, "https://european-alternatives.eu/product/proton-mail"),
+            ],
+        },
+        Product {
+            categories: &[0],
+            logo: None,
+            name: "Nextcloud",
+            slug: "nextcloud",
+            description: "All-in-one self-hosted cloud with a Node.js client.",
+            summary: "All-in-one self-hosted cloud.",
+            country: Some(Country::Germany),
+            websites: &[("Website", "https://nextcloud.com")],
+        },
+        Product {
+            categories: &[2, 1],
+            logo: None,
+            name: "Vivaldi",
+            slug: "vivaldi",
+            description: "Web browser with a built-in mail client.",
+            summary: "Web browser.",
+            country: None,
+            websites: &[],
+        },
+        Product {
+            categories: &[3],
+            logo: None,
+            name: "Photopea",
+            slug: "photopea",
+            description: "Online photo editor.",
+            summary: "Online photo editor.",
+            country: Some(Country::CzechRepublic),
+            websites: &[("Website", "https://www.photopea.com")],
+        },
+    ],
+    categories_map: phf::phf_map! {
+        "cloud-storage" => 0,
+        "email-providers" => 1,
+        "browsers" => 2,
+        "image-editors" => 3,
+    },
+    products_map: phf::phf_map! {
+        "Proton Mail" => 0,
+        "Nextcloud" => 1,
+        "Vivaldi" => 2,
+        "Photopea" => 3,
+    },
+    product_slugs_map: phf::phf_map! {
+        "proton-mail" => 0,
+        "nextcloud" => 1,
+        "vivaldi" => 2,
+        "photopea" => 3,
+    },
+    category_products: &[&[1], &[0, 2], &[2], &[3]],
+    country_products: &COUNTRY_PRODUCTS,
+    category_member_sets: OnceLock::new(),
+};
+
+/// Catalog with nothing in it, as scraped when the upstream site lists nothing.
+pub static EMPTY_CATALOG: Catalog = Catalog {
+    version: Catalog::VERSION,
+    categories: &[],
+    products: &[],
+    categories_map: phf::phf_map! {},
+    products_map: phf::phf_map! {},
+    product_slugs_map: phf::phf_map! {},
+    category_products: &[],
+    country_products: &[NO_PRODUCTS; Country::COUNT],
+    category_member_sets: OnceLock::new(),
+};
+
+pub fn repository() -> Repository {
+    Repository::new(&CATALOG)
+}
+
+pub fn empty_repository() -> Repository {
+    Repository::new(&EMPTY_CATALOG)
+}
\ No newline at end of file
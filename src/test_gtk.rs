@@ -0,0 +1,57 @@
+use crate::constants::APP_RESOURCES;
+use crate::prelude::*;
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::OnceLock;
+use std::sync::mpsc::{self, Sender};
+use std::thread;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// GTK may only be used from the thread that initialized it, while tests run
+/// on a thread each, so widget tests hand their body to one long-lived thread.
+static GTK_THREAD: OnceLock<Option<Sender<Job>>> = OnceLock::new();
+
+fn spawn_gtk_thread() -> Option<Sender<Job>> {
+    let (ready_sender, ready_receiver) = mpsc::channel();
+    let (job_sender, job_receiver) = mpsc::channel::<Job>();
+
+    thread::spawn(move || {
+        let initialized = adw::init().is_ok()
+            && gtk::gio::resources_register_include_impl(APP_RESOURCES).is_ok();
+        let _ = ready_sender.send(initialized);
+
+        if initialized {
+            for job in job_receiver {
+                job();
+            }
+        }
+    });
+
+    ready_receiver.recv().unwrap_or(false).then_some(job_sender)
+}
+
+/// Runs `test` on the GTK thread, or returns `None` without running it when
+/// GTK cannot be initialized, e.g. without a display. Panics are re-raised on
+/// the calling test's thread.
+pub fn run<F, R>(test: F) -> Option<R>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    let Some(job_sender) = GTK_THREAD.get_or_init(spawn_gtk_thread) else {
+        eprintln!("GTK is not available, skipping widget test");
+        return None;
+    };
+
+    let (result_sender, result_receiver) = mpsc::channel();
+    let job = Box::new(move || {
+        let _ = result_sender.send(panic::catch_unwind(AssertUnwindSafe(test)));
+    });
+    job_sender.send(job).ok()?;
+
+    match result_receiver.recv().ok()? {
+        Ok(result) => Some(result),
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
\ No newline at end of file
@@ -5,6 +5,7 @@ use super::widgets::{
     MainPageWidget,
     OverviewPageWidget,
     ProductPageWidget,
+    ComparisonPageWidget,
     SidebarWidget,
     SidebarCountryRowWidget,
     SidebarCategoryListWidget,
@@ -38,6 +39,10 @@ impl Ui {
         self.window.navigation().replace_with_page(NavigationPage::Main);
     }
 
+    pub fn show_empty_catalog(&self) {
+        self.window.navigation().replace_with_page(NavigationPage::Empty);
+    }
+
     pub fn window(&self) -> &WindowWidget {
         &self.window
     }
@@ -90,6 +95,11 @@ impl Ui {
             .product_page()
     }
 
+    pub fn comparison_page(&self) -> &ComparisonPageWidget {
+        self.navigation()
+            .comparison_page()
+    }
+
     pub fn downgrade(&self) -> UiWeak {
         UiWeak { window: Rc::downgrade(&self.window) }
     }
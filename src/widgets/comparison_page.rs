@@ -0,0 +1,96 @@
+use super::super::prelude::*;
+use super::page_content::PageContent;
+
+use std::cell::Cell;
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, gtk::CompositeTemplate)]
+    #[template(resource = "/pt/timarques/european_choice/comparison_page.ui")]
+    pub struct ComparisonPage {
+        #[template_child(id = "comparison-page-content")]
+        pub content: TemplateChild<PageContent>,
+        #[template_child(id = "comparison-page-grid")]
+        pub grid: TemplateChild<gtk::Grid>,
+
+        pub next_row: Cell<i32>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ComparisonPage {
+        const NAME: &'static str = "ComparisonPage";
+        type Type = super::ComparisonPage;
+        type ParentType = adw::NavigationPage;
+
+        fn class_init(class: &mut Self::Class) {
+            Self::bind_template(class);
+        }
+
+        fn instance_init(object: &glib::subclass::InitializingObject<Self>) {
+            object.init_template();
+        }
+    }
+
+    impl ObjectImpl for ComparisonPage {}
+    impl WidgetImpl for ComparisonPage {}
+    impl NavigationPageImpl for ComparisonPage {}
+}
+
+glib::wrapper! {
+    pub struct ComparisonPage(ObjectSubclass<imp::ComparisonPage>)
+        @extends adw::NavigationPage, gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl ComparisonPage {
+
+    pub fn clear(&self) {
+        let grid = &self.imp().grid;
+        while let Some(child) = grid.first_child() {
+            grid.remove(&child);
+        }
+        self.imp().next_row.set(0);
+    }
+
+    fn attach_label(&self, label: &gtk::Label, column: i32) {
+        self.imp().grid.attach(label, column, self.imp().next_row.get(), 1, 1);
+    }
+
+    fn build_label(text: &str, css_class: &str) -> gtk::Label {
+        gtk::Label::builder()
+            .label(text)
+            .xalign(0.0)
+            .yalign(0.0)
+            .wrap(true)
+            .wrap_mode(gtk::pango::WrapMode::WordChar)
+            .selectable(true)
+            .css_classes([css_class])
+            .build()
+    }
+
+    /// Starts a new comparison with one column per product name.
+    pub fn set_products(&self, names: &[&str]) {
+        self.clear();
+
+        for (column, name) in (1..).zip(names) {
+            self.attach_label(&Self::build_label(name, "title-4"), column);
+        }
+        self.imp().next_row.set(1);
+    }
+
+    /// Adds a labelled row, one value per product column. Empty values are shown
+    /// as a dash so columns stay aligned.
+    pub fn append_row(&self, label: &str, values: &[String]) {
+        self.attach_label(&Self::build_label(label, "heading"), 0);
+
+        for (column, value) in (1..).zip(values) {
+            let text = if value.is_empty() { "\u{2014}" } else { value.as_str() };
+            self.attach_label(&Self::build_label(text, "body"), column);
+        }
+
+        let imp = self.imp();
+        imp.next_row.set(imp.next_row.get() + 1);
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,45 @@
+use super::super::prelude::*;
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, gtk::CompositeTemplate)]
+    #[template(resource = "/pt/timarques/european_choice/empty_page.ui")]
+    pub struct EmptyPage {
+        #[template_child(id = "empty-page-status")]
+        pub status_page: TemplateChild<adw::StatusPage>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for EmptyPage {
+        const NAME: &'static str = "EmptyPage";
+        type Type = super::EmptyPage;
+        type ParentType = adw::NavigationPage;
+
+        fn class_init(class: &mut Self::Class) {
+            Self::bind_template(class);
+        }
+
+        fn instance_init(object: &glib::subclass::InitializingObject<Self>) {
+            object.init_template();
+        }
+    }
+
+    impl ObjectImpl for EmptyPage {}
+    impl WidgetImpl for EmptyPage {}
+    impl NavigationPageImpl for EmptyPage {}
+}
+
+glib::wrapper! {
+    pub struct EmptyPage(ObjectSubclass<imp::EmptyPage>)
+        @extends adw::NavigationPage, gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl EmptyPage {
+
+    pub fn status_page(&self) -> &adw::StatusPage {
+        &self.imp().status_page
+    }
+
+}
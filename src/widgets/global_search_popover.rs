@@ -0,0 +1,225 @@
+use super::super::prelude::*;
+
+use std::cell::RefCell;
+use std::sync::OnceLock;
+use glib::subclass::Signal;
+
+const SEARCH_CHANGED_SIGNAL: &str = "search-changed";
+const RESULT_ACTIVATED_SIGNAL: &str = "result-activated";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, glib::Enum)]
+#[enum_type(name = "GlobalSearchResultKind")]
+pub enum GlobalSearchResultKind {
+    Product,
+    Category,
+    Country,
+    Recent,
+    Favorite,
+}
+
+impl GlobalSearchResultKind {
+    pub const fn section_title(self) -> &'static str {
+        match self {
+            Self::Product => "Products",
+            Self::Category => "Categories",
+            Self::Country => "Countries",
+            Self::Recent => "Recent",
+            Self::Favorite => "Favorites",
+        }
+    }
+}
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, gtk::CompositeTemplate)]
+    #[template(resource = "/pt/timarques/european_choice/global_search_popover.ui")]
+    pub struct GlobalSearchPopover {
+        #[template_child(id = "global-search-popover-entry")]
+        pub search_entry: TemplateChild<gtk::SearchEntry>,
+        #[template_child(id = "global-search-popover-list-box")]
+        pub list_box: TemplateChild<gtk::ListBox>,
+
+        pub entries: RefCell<Vec<Option<(GlobalSearchResultKind, u32)>>>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for GlobalSearchPopover {
+        const NAME: &'static str = "GlobalSearchPopover";
+        type Type = super::GlobalSearchPopover;
+        type ParentType = gtk::Popover;
+
+        fn class_init(class: &mut Self::Class) {
+            Self::bind_template(class);
+        }
+
+        fn instance_init(object: &glib::subclass::InitializingObject<Self>) {
+            object.init_template();
+        }
+    }
+
+    impl ObjectImpl for GlobalSearchPopover {
+        fn constructed(&self) {
+            self.parent_constructed();
+            self.obj().setup_search_entry();
+            self.obj().setup_list_box();
+        }
+
+        fn signals() -> &'static [Signal] {
+            static SIGNALS: OnceLock<[Signal; 2]> = OnceLock::new();
+            SIGNALS.get_or_init(|| {
+                [
+                    Signal::builder(SEARCH_CHANGED_SIGNAL)
+                        .param_types([String::static_type()])
+                        .build(),
+                    Signal::builder(RESULT_ACTIVATED_SIGNAL)
+                        .param_types([GlobalSearchResultKind::static_type(), u32::static_type()])
+                        .build(),
+                ]
+            })
+        }
+    }
+
+    impl WidgetImpl for GlobalSearchPopover {}
+    impl PopoverImpl for GlobalSearchPopover {}
+}
+
+glib::wrapper! {
+    pub struct GlobalSearchPopover(ObjectSubclass<imp::GlobalSearchPopover>)
+        @extends gtk::Popover, gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget, gtk::Native, gtk::ShortcutManager;
+}
+
+impl Default for GlobalSearchPopover {
+    fn default() -> Self {
+        glib::Object::new()
+    }
+}
+
+impl GlobalSearchPopover {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn setup_search_entry(&self) {
+        let this_weak = self.downgrade();
+        self.imp().search_entry.connect_search_changed(move |entry| {
+            if let Some(this) = this_weak.upgrade() {
+                this.emit_by_name::<()>(SEARCH_CHANGED_SIGNAL, &[&entry.text().to_string()]);
+            }
+        });
+
+        let this_weak = self.downgrade();
+        self.imp().search_entry.connect_activate(move |_| {
+            if let Some(this) = this_weak.upgrade()
+                && let Some(row) = this.first_result_row()
+            {
+                row.activate();
+            }
+        });
+    }
+
+    fn setup_list_box(&self) {
+        let placeholder = gtk::Label::new(Some("No Results"));
+        placeholder.add_css_class("dim-label");
+        placeholder.set_margin_top(12);
+        placeholder.set_margin_bottom(12);
+        self.imp().list_box.set_placeholder(Some(&placeholder));
+
+        let this_weak = self.downgrade();
+        self.imp().list_box.connect_row_activated(move |_, row| {
+            if let Some(this) = this_weak.upgrade() {
+                this.handle_row_activated(row);
+            }
+        });
+    }
+
+    fn handle_row_activated(&self, row: &gtk::ListBoxRow) {
+        let Ok(position) = usize::try_from(row.index()) else {
+            return;
+        };
+
+        let entry = self.imp().entries.borrow().get(position).copied().flatten();
+        if let Some((kind, index)) = entry {
+            self.popdown();
+            self.emit_by_name::<()>(RESULT_ACTIVATED_SIGNAL, &[&kind, &index]);
+        }
+    }
+
+    fn first_result_row(&self) -> Option<gtk::ListBoxRow> {
+        let position = self.imp().entries.borrow().iter().position(Option::is_some)?;
+        self.imp().list_box.row_at_index(i32::try_from(position).ok()?)
+    }
+
+    pub fn popup_search(&self) {
+        self.popup();
+        let search_entry = &self.imp().search_entry;
+        search_entry.grab_focus();
+        search_entry.select_region(0, -1);
+    }
+
+    pub fn search_text(&self) -> glib::GString {
+        self.imp().search_entry.text()
+    }
+
+    pub fn clear_results(&self) {
+        self.imp().list_box.remove_all();
+        self.imp().entries.borrow_mut().clear();
+    }
+
+    pub fn append_section(&self, kind: GlobalSearchResultKind) {
+        let label = gtk::Label::new(Some(kind.section_title()));
+        label.set_xalign(0.0);
+        label.add_css_class("heading");
+
+        let row = gtk::ListBoxRow::builder()
+            .child(&label)
+            .activatable(false)
+            .selectable(false)
+            .focusable(false)
+            .build();
+
+        self.imp().list_box.append(&row);
+        self.imp().entries.borrow_mut().push(None);
+    }
+
+    pub fn append_result(&self, kind: GlobalSearchResultKind, index: usize, title: &str, subtitle: Option<&str>) {
+        let row = adw::ActionRow::builder()
+            .title(glib::markup_escape_text(title))
+            .activatable(true)
+            .build();
+
+        if let Some(subtitle) = subtitle {
+            row.set_subtitle(&glib::markup_escape_text(subtitle));
+        }
+
+        self.imp().list_box.append(&row);
+        self.imp().entries.borrow_mut().push(Some((kind, index as u32)));
+    }
+
+    pub fn connect_search_changed<F>(&self, callback: F) -> glib::SignalHandlerId
+    where
+        F: Fn(&Self, &str) + 'static,
+    {
+        self.connect_local(SEARCH_CHANGED_SIGNAL, true, move |values| {
+            let this = values[0].get::<Self>().unwrap();
+            let text = values[1].get::<String>().unwrap();
+            callback(&this, &text);
+            None
+        })
+    }
+
+    pub fn connect_result_activated<F>(&self, callback: F) -> glib::SignalHandlerId
+    where
+        F: Fn(&Self, GlobalSearchResultKind, usize) + 'static,
+    {
+        self.connect_local(RESULT_ACTIVATED_SIGNAL, true, move |values| {
+            let this = values[0].get::<Self>().unwrap();
+            let kind = values[1].get::<GlobalSearchResultKind>().unwrap();
+            let index = values[2].get::<u32>().unwrap();
+            callback(&this, kind, index as usize);
+            None
+        })
+    }
+}
\ No newline at end of file
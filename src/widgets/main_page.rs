@@ -1,6 +1,7 @@
 use super::super::prelude::*;
 use super::overview_page::OverviewPage;
 use super::sidebar::Sidebar;
+use super::super::sync_guard::SyncGuard;
 
 use std::cell::RefCell;
 
@@ -20,6 +21,8 @@ mod imp {
 
         #[property(get, set)]
         pub title: RefCell<String>,
+
+        pub selection_guard: SyncGuard,
     }
 
     #[glib::object_subclass]
@@ -59,11 +62,19 @@ glib::wrapper! {
 
 impl MainPage {
 
+    // Groups passed over while scrolling to a selected row would otherwise
+    // select their own rows on the way, so the sidebar only follows the overview
+    // once the scroll has settled.
     fn setup_overview(&self) {
         let this_weak = self.downgrade();
-        self.overview().connect_active_group_changed(move |_, group| {
-            if let Some(this) = this_weak.upgrade() {
-                this.sidebar().category_list().select_row_by_index(group.index() as usize);
+        self.overview().connect_active_group_changed(move |overview, group| {
+            if
+                let Some(this) = this_weak.upgrade()
+                && !overview.is_scrolling_to_group()
+            {
+                this.imp().selection_guard.run(|| {
+                    this.sidebar().category_list().select_row_by_index(group.index() as usize);
+                });
             }
         });
     }
@@ -72,7 +83,9 @@ impl MainPage {
         let this_weak = self.downgrade();
         self.sidebar().category_list().connect_row_selected(move |_, row_index, _| {
             if let Some(this) = this_weak.upgrade() {
-                this.overview().scroll_to_group_index(row_index);
+                this.imp().selection_guard.run(|| {
+                    this.overview().scroll_to_group_index(row_index);
+                });
             }
         });
     }
@@ -89,4 +102,11 @@ impl MainPage {
         self.imp().split_view.set_collapsed(collapse);
     }
 
+    pub fn show_sidebar(&self) {
+        let split_view = &self.imp().split_view;
+        if split_view.is_collapsed() {
+            split_view.set_show_content(false);
+        }
+    }
+
 }
\ No newline at end of file
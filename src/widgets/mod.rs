@@ -1,16 +1,19 @@
 #![allow(unused_imports)]
 mod window;
 mod loading_page;
+mod empty_page;
 mod main_page;
 mod page_content;
 mod navigation;
 
 mod product_page;
+mod comparison_page;
 mod product_row;
 
 mod sidebar;
 mod sidebar_row;
 mod sidebar_search_row;
+mod global_search_popover;
 mod sidebar_country_row;
 mod sidebar_country_item;
 mod sidebar_primary_list;
@@ -31,16 +34,21 @@ pub use main_page::MainPage as MainPageWidget;
 pub use product_page::ProductPage as ProductPageWidget;
 pub use product_page::ProductRowType;
 pub use product_row::ProductRow as ProductRowWidget;
+pub use comparison_page::ComparisonPage as ComparisonPageWidget;
 
 pub use overview_page::OverviewPage as OverviewPageWidget;
 pub use overview_product_row::OverviewProductRow as OverviewProductRowWidget;
 pub use overview_product_group::OverviewProductGroup as OverviewProductGroupWidget;
 
 pub use sidebar::Sidebar as SidebarWidget;
+pub use sidebar::SidebarFilters;
 pub use sidebar_row::SidebarRow as SidebarRowWidget;
 pub use sidebar_search_row::SidebarSearchRow as SidebarSearchRowWidget;
 pub use sidebar_search_row::SidebarSearchRowState as SidebarSearchRowState;
+pub use global_search_popover::GlobalSearchPopover as GlobalSearchPopoverWidget;
+pub use global_search_popover::GlobalSearchResultKind;
 pub use sidebar_country_row::SidebarCountryRow as SidebarCountryRowWidget;
+pub use sidebar_country_row::SidebarCountryRowState as SidebarCountryRowState;
 pub use sidebar_country_item::SidebarCountryItem as SidebarCountryItemWidget;
 pub use sidebar_primary_list::SidebarPrimaryList as SidebarPrimaryListWidget;
 pub use sidebar_category_list::SidebarCategoryList as SidebarCategoryListWidget;
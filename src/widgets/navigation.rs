@@ -1,13 +1,22 @@
 use super::super::prelude::*;
 use super::loading_page::LoadingPage;
+use super::empty_page::EmptyPage;
 use super::main_page::MainPage;
 use super::product_page::ProductPage;
+use super::comparison_page::ComparisonPage;
+use super::sidebar::SidebarFilters;
+
+use std::cell::RefCell;
+
+const MAX_FILTER_HISTORY: usize = 16;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NavigationPage {
     Loading,
+    Empty,
     Main,
     Product,
+    Comparison,
 }
 
 mod imp {
@@ -20,10 +29,16 @@ mod imp {
         pub view: TemplateChild<adw::NavigationView>,
         #[template_child(id = "navigation-loading-page")]
         pub loading_page: TemplateChild<LoadingPage>,
+        #[template_child(id = "navigation-empty-page")]
+        pub empty_page: TemplateChild<EmptyPage>,
         #[template_child(id = "navigation-main-page")]
         pub main_page: TemplateChild<MainPage>,
         #[template_child(id = "product-page")]
         pub product_page: TemplateChild<ProductPage>,
+        #[template_child(id = "comparison-page")]
+        pub comparison_page: TemplateChild<ComparisonPage>,
+
+        pub filter_history: RefCell<Vec<SidebarFilters>>,
     }
 
     #[glib::object_subclass]
@@ -41,7 +56,12 @@ mod imp {
         }
     }
 
-    impl ObjectImpl for Navigation {}
+    impl ObjectImpl for Navigation {
+        fn constructed(&self) {
+            self.parent_constructed();
+            self.obj().setup_filter_history();
+        }
+    }
     impl WidgetImpl for Navigation {}
     impl BinImpl for Navigation {}
 }
@@ -57,6 +77,10 @@ impl Navigation {
         &self.imp().loading_page
     }
 
+    pub fn empty_page(&self) -> &EmptyPage {
+        &self.imp().empty_page
+    }
+
     pub fn main_page(&self) -> &MainPage {
         &self.imp().main_page
     }
@@ -65,13 +89,19 @@ impl Navigation {
         &self.imp().product_page
     }
 
+    pub fn comparison_page(&self) -> &ComparisonPage {
+        &self.imp().comparison_page
+    }
+
     pub fn push_page(&self, page: NavigationPage) {
         let view: &adw::NavigationView = &self.imp().view;
 
         let widget: &adw::NavigationPage = match page {
             NavigationPage::Loading => self.loading_page().upcast_ref(),
+            NavigationPage::Empty => self.empty_page().upcast_ref(),
             NavigationPage::Main => self.main_page().upcast_ref(),
             NavigationPage::Product => self.product_page().upcast_ref(),
+            NavigationPage::Comparison => self.comparison_page().upcast_ref(),
         };
 
         view.push(widget);
@@ -82,8 +112,10 @@ impl Navigation {
 
         let widget: adw::NavigationPage = match page {
             NavigationPage::Loading => self.loading_page().clone().upcast(),
+            NavigationPage::Empty => self.empty_page().clone().upcast(),
             NavigationPage::Main => self.main_page().clone().upcast(),
             NavigationPage::Product => self.product_page().clone().upcast(),
+            NavigationPage::Comparison => self.comparison_page().clone().upcast(),
         };
 
         view.replace(&[widget]);
@@ -95,10 +127,14 @@ impl Navigation {
 
         if self.loading_page().tag() == tag {
             Some(NavigationPage::Loading)
+        } else if self.empty_page().tag() == tag {
+            Some(NavigationPage::Empty)
         } else if self.main_page().tag() == tag {
             Some(NavigationPage::Main)
         } else if self.product_page().tag() == tag {
             Some(NavigationPage::Product)
+        } else if self.comparison_page().tag() == tag {
+            Some(NavigationPage::Comparison)
         } else {
             None
         }
@@ -107,4 +143,98 @@ impl Navigation {
     pub fn pop(&self) -> bool {
         self.imp().view.pop()
     }
-}
\ No newline at end of file
+
+    /// Remembers the sidebar filters before they are cleared, unless there is
+    /// nothing to restore.
+    pub fn push_filters(&self, filters: SidebarFilters) {
+        Self::push_filter_history(&mut self.imp().filter_history.borrow_mut(), filters);
+    }
+
+    /// Appends to `history`, dropping the oldest entry once it holds
+    /// `MAX_FILTER_HISTORY` of them.
+    fn push_filter_history(history: &mut Vec<SidebarFilters>, filters: SidebarFilters) {
+        if filters.is_empty() {
+            return;
+        }
+
+        if history.len() == MAX_FILTER_HISTORY {
+            history.remove(0);
+        }
+        history.push(filters);
+    }
+
+    fn is_at_root(&self) -> bool {
+        let view = &self.imp().view;
+        view.visible_page()
+            .is_none_or(|page| view.previous_page(&page).is_none())
+    }
+
+    // Popping runs for the back button, swipe-back and `pop` alike, so each of
+    // them puts back the filters that were cleared on the popped page. Once the
+    // root page is shown again there is nothing left to go back to.
+    fn setup_filter_history(&self) {
+        let this_weak = self.downgrade();
+        self.imp().view.connect_popped(move |_, _| {
+            if let Some(this) = this_weak.upgrade() {
+                let filters = this.imp().filter_history.borrow_mut().pop();
+                if let Some(filters) = filters {
+                    this.main_page().sidebar().restore_filters(&filters);
+                }
+                if this.is_at_root() {
+                    this.imp().filter_history.borrow_mut().clear();
+                }
+            }
+        });
+
+        let this_weak = self.downgrade();
+        self.imp().view.connect_replaced(move |_| {
+            if let Some(this) = this_weak.upgrade() {
+                this.imp().filter_history.borrow_mut().clear();
+            }
+        });
+    }
+
+    pub fn connect_page_changed<F>(&self, callback: F) -> glib::SignalHandlerId
+    where
+        F: Fn(&Self, Option<NavigationPage>) + 'static,
+    {
+        let this_weak = self.downgrade();
+        self.imp().view.connect_visible_page_notify(move |_| {
+            if let Some(this) = this_weak.upgrade() {
+                callback(&this, this.page());
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn search(text: &str) -> SidebarFilters {
+        SidebarFilters { search_text: text.to_string(), country_indices: Vec::new() }
+    }
+
+    #[test]
+    fn empty_filters_are_not_remembered() {
+        let mut history = Vec::new();
+
+        Navigation::push_filter_history(&mut history, SidebarFilters::default());
+        assert!(history.is_empty());
+
+        Navigation::push_filter_history(&mut history, SidebarFilters { search_text: String::new(), country_indices: vec![3] });
+        assert_eq!(history.len(), 1);
+    }
+
+    #[test]
+    fn filter_history_drops_the_oldest_entries_past_the_cap() {
+        let mut history = Vec::new();
+        for entry in 0..=MAX_FILTER_HISTORY {
+            Navigation::push_filter_history(&mut history, search(&entry.to_string()));
+        }
+
+        assert_eq!(history.len(), MAX_FILTER_HISTORY);
+        assert_eq!(history.first(), Some(&search("1")));
+        assert_eq!(history.last(), Some(&search(&MAX_FILTER_HISTORY.to_string())));
+    }
+}
@@ -1,13 +1,18 @@
 use super::super::prelude::*;
 use super::overview_product_group::OverviewProductGroup;
 use super::page_content::PageContent;
-use super::super::controllers::GroupScrollController;
+use super::super::controllers::{GroupScrollController, ScrollEasing};
 use super::super::ordered_map::OrderedMap;
 
 use std::cell::{Cell, Ref, RefCell, OnceCell};
 use std::sync::OnceLock;
+use std::time::Duration;
 
 const ACTIVE_GROUP_CHANGED_SIGNAL: &str = "active-group-changed";
+const COMPARE_REQUESTED_SIGNAL: &str = "compare-requested";
+const SCROLL_TOP_BUTTON_THRESHOLD: f64 = 0.1;
+const GROUP_SCROLL_DURATION: Duration = Duration::from_millis(300);
+const GROUP_SCROLL_EASING: ScrollEasing = ScrollEasing::EaseOutCubic;
 
 mod imp {
     use super::*;
@@ -20,9 +25,17 @@ mod imp {
         pub box_container: TemplateChild<gtk::Box>,
         #[template_child(id = "overview-page-content")]
         pub content: TemplateChild<PageContent>,
+        #[template_child(id = "overview-page-scroll-top-button")]
+        pub scroll_top_button: TemplateChild<gtk::Button>,
+        #[template_child(id = "overview-page-compare-button")]
+        pub compare_button: TemplateChild<gtk::Button>,
+        #[template_child(id = "overview-page-scroll-progress")]
+        pub scroll_progress: TemplateChild<gtk::ProgressBar>,
 
         #[property(get, set)]
         pub subtitle: RefCell<String>,
+        #[property(get, set)]
+        pub compare_mode: Cell<bool>,
 
         pub groups: RefCell<OrderedMap<OverviewProductGroup>>,
         pub active_index: Cell<Option<usize>>,
@@ -49,13 +62,16 @@ mod imp {
         fn constructed(&self) {
             self.parent_constructed();
             self.obj().setup_scroll_controller();
+            self.obj().setup_scroll_top_button();
+            self.obj().setup_compare_controls();
         }
 
         fn signals() -> &'static [glib::subclass::Signal] {
-            static SIGNALS: OnceLock<[glib::subclass::Signal; 1]> = OnceLock::new();
+            static SIGNALS: OnceLock<[glib::subclass::Signal; 2]> = OnceLock::new();
             SIGNALS.get_or_init(|| {
                 [
-                    glib::subclass::Signal::builder(ACTIVE_GROUP_CHANGED_SIGNAL).param_types([OverviewProductGroup::static_type()]).build()
+                    glib::subclass::Signal::builder(ACTIVE_GROUP_CHANGED_SIGNAL).param_types([OverviewProductGroup::static_type()]).build(),
+                    glib::subclass::Signal::builder(COMPARE_REQUESTED_SIGNAL).build()
                 ]
             })
         }
@@ -83,15 +99,87 @@ impl OverviewPage {
                 this.set_active_group_index(index);
             }
         };
+        let this_weak = self.downgrade();
+        let progress_handler = move |progress| {
+            if let Some(this) = this_weak.upgrade() {
+                this.update_scroll_progress(progress);
+            }
+        };
         let controller = GroupScrollController::new(
             this,
             box_container,
             scrolled_window,
             handler
-        );
+        )
+            .with_animation_duration(GROUP_SCROLL_DURATION)
+            .with_easing(GROUP_SCROLL_EASING)
+            .with_on_progress(progress_handler);
         self.imp().scroll_controller.set(controller).ok().expect("controller set once");
     }
 
+    /// A thin bar at the top hints at how far a long programmatic scroll has
+    /// come, and goes away once it lands.
+    fn update_scroll_progress(&self, progress: f64) {
+        let scroll_progress = &self.imp().scroll_progress;
+        scroll_progress.set_fraction(progress);
+        scroll_progress.set_visible(Self::is_scroll_in_progress(progress));
+    }
+
+    fn is_scroll_in_progress(progress: f64) -> bool {
+        progress < 1.0
+    }
+
+    fn setup_scroll_top_button(&self) {
+        let this_weak = self.downgrade();
+        self.imp().scroll_top_button.connect_clicked(move |_| {
+            if let Some(this) = this_weak.upgrade() {
+                this.scroll_to_top();
+            }
+        });
+
+        let this_weak = self.downgrade();
+        self.imp().content.scrolled_window().vadjustment().connect_value_changed(move |_| {
+            if let Some(this) = this_weak.upgrade() {
+                this.update_scroll_top_button();
+            }
+        });
+    }
+
+    fn setup_compare_controls(&self) {
+        let this_weak = self.downgrade();
+        self.imp().compare_button.connect_clicked(move |_| {
+            if let Some(this) = this_weak.upgrade() {
+                this.emit_by_name::<()>(COMPARE_REQUESTED_SIGNAL, &[]);
+            }
+        });
+
+        self.connect_compare_mode_notify(|this| {
+            let compare_mode = this.compare_mode();
+            for group in this.groups().values() {
+                for row in group.rows().values() {
+                    row.set_compare_mode(compare_mode);
+                }
+            }
+        });
+    }
+
+    pub fn set_compare_ready(&self, ready: bool) {
+        self.imp().compare_button.set_sensitive(ready);
+    }
+
+    fn update_scroll_top_button(&self) {
+        let fraction = self.imp()
+            .scroll_controller
+            .get()
+            .map_or(0.0, GroupScrollController::scroll_fraction);
+
+        self.imp().scroll_top_button.set_visible(Self::should_show_scroll_top_button(fraction));
+    }
+
+    pub fn should_show_scroll_top_button(scroll_fraction: f64) -> bool {
+        scroll_fraction > SCROLL_TOP_BUTTON_THRESHOLD
+    }
+
     pub fn add_group(&self, group: OverviewProductGroup) -> usize {
         let index = group.index() as usize;
         let imp = self.imp();
@@ -128,6 +216,18 @@ impl OverviewPage {
         self.imp().groups.borrow()
     }
 
+    pub fn set_category_colors(&self, enabled: bool) {
+        for group in self.groups().values() {
+            group.set_category_color(enabled);
+        }
+    }
+
+    pub fn set_animate_row_reveal(&self, animate: bool) {
+        for group in self.groups().values() {
+            group.set_animate_reveal(animate);
+        }
+    }
+
     fn set_active_group_index(&self, index: usize) -> bool {
         let imp = self.imp();
 
@@ -145,6 +245,37 @@ impl OverviewPage {
         }
     }
 
+    /// Moves the active group to the first visible one when filtering has hidden
+    /// the current one, or when there is none yet. Group positions may not be
+    /// laid out right after a filter change, so this doesn't rely on scrolling.
+    pub fn refresh_active_group(&self) -> bool {
+        let group_index = Self::group_to_activate(
+            self.active_group_index(),
+            self.groups().iter().map(|(index, group)| (index, group.is_visible()))
+        );
+
+        group_index.is_some_and(|index| self.set_active_group_index(index))
+    }
+
+    /// The first visible group when the active one is hidden or gone, given
+    /// each group's index and visibility in display order.
+    fn group_to_activate<I>(active_index: Option<usize>, groups_visibility: I) -> Option<usize>
+    where
+        I: IntoIterator<Item = (usize, bool)>,
+    {
+        let mut first_visible_index = None;
+        for (index, visible) in groups_visibility {
+            if visible && Some(index) == active_index {
+                return None;
+            }
+            if visible && first_visible_index.is_none() {
+                first_visible_index = Some(index);
+            }
+        }
+
+        first_visible_index
+    }
+
     pub fn get_active_group(&self) -> Option<Ref<'_, OverviewProductGroup>> {
         self
             .imp()
@@ -164,6 +295,13 @@ impl OverviewPage {
             .scroll_to(index)
     }
 
+    pub fn is_scrolling_to_group(&self) -> bool {
+        self.imp()
+            .scroll_controller
+            .get()
+            .is_some_and(GroupScrollController::is_animating)
+    }
+
     pub fn scroll_to_top(&self) -> bool {
         self.imp()
             .scroll_controller
@@ -183,4 +321,59 @@ impl OverviewPage {
             None
         })
     }
+
+    pub fn connect_compare_requested<F>(&self, callback: F) -> glib::SignalHandlerId
+    where
+        F: Fn(&Self) + 'static,
+    {
+        self.connect_local(COMPARE_REQUESTED_SIGNAL, false, move |values| {
+            let this = values[0].get::<Self>().unwrap();
+            callback(&this);
+            None
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn visible_active_group_stays_active() {
+        let groups = [(2, false), (0, true), (1, true)];
+        assert_eq!(OverviewPage::group_to_activate(Some(1), groups), None);
+    }
+
+    #[test]
+    fn hidden_active_group_moves_to_the_first_visible_one() {
+        let groups = [(2, false), (0, true), (1, true)];
+        assert_eq!(OverviewPage::group_to_activate(Some(2), groups), Some(0));
+    }
+
+    #[test]
+    fn stale_or_missing_active_index_moves_to_the_first_visible_one() {
+        let groups = [(2, false), (0, false), (1, true)];
+        assert_eq!(OverviewPage::group_to_activate(Some(7), groups), Some(1));
+        assert_eq!(OverviewPage::group_to_activate(None, groups), Some(1));
+    }
+
+    #[test]
+    fn nothing_to_activate_when_every_group_is_hidden() {
+        assert_eq!(OverviewPage::group_to_activate(Some(0), [(0, false), (1, false)]), None);
+    }
+
+    #[test]
+    fn scroll_progress_is_hidden_once_the_scroll_lands() {
+        assert!(OverviewPage::is_scroll_in_progress(0.0));
+        assert!(OverviewPage::is_scroll_in_progress(0.75));
+        assert!(!OverviewPage::is_scroll_in_progress(1.0));
+    }
+
+    #[test]
+    fn scroll_top_button_shows_past_the_threshold() {
+        assert!(!OverviewPage::should_show_scroll_top_button(0.0));
+        assert!(!OverviewPage::should_show_scroll_top_button(SCROLL_TOP_BUTTON_THRESHOLD));
+        assert!(OverviewPage::should_show_scroll_top_button(SCROLL_TOP_BUTTON_THRESHOLD + 0.01));
+        assert!(OverviewPage::should_show_scroll_top_button(1.0));
+    }
 }
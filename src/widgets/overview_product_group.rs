@@ -5,6 +5,8 @@ use super::overview_product_row::OverviewProductRow;
 use std::cell::{Ref, RefCell, Cell};
 use std::collections::HashMap;
 
+const ROW_REVEAL_DURATION_MS: u32 = 200;
+
 mod imp {
     use super::*;
 
@@ -14,6 +16,8 @@ mod imp {
     pub struct OverviewProductGroup {
         #[template_child(id = "overview-product-group-list-box")]
         pub list_box: TemplateChild<gtk::ListBox>,
+        #[template_child(id = "overview-product-group-match-count")]
+        pub match_count_label: TemplateChild<gtk::Label>,
 
         #[property(get, set)]
         pub title: RefCell<String>,
@@ -21,6 +25,8 @@ mod imp {
         pub description: RefCell<String>,
         #[property(get, set)]
         pub index: Cell<u32>,
+        #[property(get, set)]
+        pub animate_reveal: Cell<bool>,
 
         pub rows: RefCell<HashMap<usize, OverviewProductRow>>,
     }
@@ -66,7 +72,17 @@ impl OverviewProductGroup {
     }
 
     pub fn from_category(category: &Category, index: usize) -> Self {
-        Self::new(category.name, category.description, index)
+        let this = Self::new(category.name, category.description, index);
+        this.add_css_class(&category.color_class());
+        this
+    }
+
+    pub fn set_category_color(&self, enabled: bool) {
+        if enabled {
+            self.add_css_class("category-color");
+        } else {
+            self.remove_css_class("category-color");
+        }
     }
 
     pub fn append_row(&self, row: OverviewProductRow) -> usize {
@@ -88,9 +104,11 @@ impl OverviewProductGroup {
         let rows = self.rows();
 
         for (_, row) in rows.iter() {
-            row.set_visible(true);
+            self.reveal_row(row);
         }
+        drop(rows);
 
+        self.update_match_count();
         self.set_visible(true);
     }
 
@@ -98,16 +116,114 @@ impl OverviewProductGroup {
     where
         F: Fn(&OverviewProductRow) -> bool,
     {
-        let mut group_should_be_visible = false;
         let rows = self.rows();
 
         for (_, row) in rows.iter() {
-            let should_show_row = predicate(row);
-            row.set_visible(should_show_row);
-            group_should_be_visible = group_should_be_visible || should_show_row;
+            if predicate(row) {
+                self.reveal_row(row);
+            } else {
+                row.set_visible(false);
+            }
+        }
+        drop(rows);
+
+        self.update_match_count();
+        self.set_visible(self.has_visible_rows());
+    }
+
+    pub fn visible_row_count(&self) -> usize {
+        self.rows().values().filter(|row| row.is_visible()).count()
+    }
+
+    pub fn has_visible_rows(&self) -> bool {
+        self.rows().values().any(WidgetExt::is_visible)
+    }
+
+    fn update_match_count(&self) {
+        let label = Self::match_count_label(self.visible_row_count(), self.rows().len());
+        let match_count_label = &self.imp().match_count_label;
+        match_count_label.set_visible(label.is_some());
+        match_count_label.set_label(label.as_deref().unwrap_or_default());
+    }
+
+    /// Only shown while a filter hides some of the group's products.
+    fn match_count_label(visible_rows: usize, total_rows: usize) -> Option<String> {
+        (visible_rows > 0 && visible_rows < total_rows)
+            .then(|| format!("{visible_rows} of {total_rows} products"))
+    }
+
+    fn reveal_row(&self, row: &OverviewProductRow) {
+        if row.is_visible() {
+            return;
+        }
+
+        row.set_visible(true);
+        if !Self::should_animate_reveal(self.animate_reveal(), Self::animations_enabled()) {
+            row.set_opacity(1.0);
+            return;
         }
 
-        self.set_visible(group_should_be_visible);
+        let target = adw::PropertyAnimationTarget::new(row, "opacity");
+        let animation = adw::TimedAnimation::new(row, 0.0, 1.0, ROW_REVEAL_DURATION_MS, target);
+        animation.set_easing(adw::Easing::EaseOutCubic);
+        animation.play();
     }
 
-}
\ No newline at end of file
+    fn animations_enabled() -> bool {
+        gtk::Settings::default().is_some_and(|settings| settings.is_gtk_enable_animations())
+    }
+
+    pub const fn should_animate_reveal(animate_reveal: bool, animations_enabled: bool) -> bool {
+        animate_reveal && animations_enabled
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_catalog;
+    use crate::test_gtk;
+
+    fn filtered_group(shown: &'static [usize]) -> Option<(usize, bool, bool)> {
+        test_gtk::run(move || {
+            let group = OverviewProductGroup::new("Products", "Every test product.", 0);
+            for (index, product) in test_catalog::CATALOG.products.iter().enumerate() {
+                group.append_row(OverviewProductRow::from_product(product, index));
+            }
+
+            group.apply_row_filter(|row| shown.contains(&(row.index() as usize)));
+            (group.visible_row_count(), group.has_visible_rows(), group.is_visible())
+        })
+    }
+
+    #[test]
+    fn filter_leaves_the_matching_rows_visible() {
+        if let Some(counts) = filtered_group(&[0, 2]) {
+            assert_eq!(counts, (2, true, true));
+        }
+    }
+
+    #[test]
+    fn filter_without_matches_hides_the_group() {
+        if let Some(counts) = filtered_group(&[]) {
+            assert_eq!(counts, (0, false, false));
+        }
+    }
+
+    #[test]
+    fn match_count_is_shown_only_while_filtering() {
+        assert_eq!(OverviewProductGroup::match_count_label(2, 4).as_deref(), Some("2 of 4 products"));
+        assert_eq!(OverviewProductGroup::match_count_label(1, 4).as_deref(), Some("1 of 4 products"));
+        assert_eq!(OverviewProductGroup::match_count_label(4, 4), None);
+        assert_eq!(OverviewProductGroup::match_count_label(0, 4), None);
+    }
+
+    #[test]
+    fn reveal_animates_only_when_motion_is_allowed() {
+        assert!(OverviewProductGroup::should_animate_reveal(true, true));
+        assert!(!OverviewProductGroup::should_animate_reveal(true, false));
+        assert!(!OverviewProductGroup::should_animate_reveal(false, true));
+        assert!(!OverviewProductGroup::should_animate_reveal(false, false));
+    }
+}
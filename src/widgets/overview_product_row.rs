@@ -1,6 +1,10 @@
 use crate::prelude::*;
 use crate::models::Product;
 use std::cell::{RefCell, Cell};
+use std::sync::OnceLock;
+use glib::subclass::Signal;
+
+const COMPARE_TOGGLED_SIGNAL: &str = "compare-toggled";
 
 mod imp {
 
@@ -12,10 +16,14 @@ mod imp {
     pub struct OverviewProductRow {
         #[template_child(id = "overview-product-row-logo")]
         pub logo_image: TemplateChild<gtk::Image>,
+        #[template_child(id = "overview-product-row-initials")]
+        pub initials_label: TemplateChild<gtk::Label>,
         #[template_child(id = "overview-product-row-country")]
         pub country_image: TemplateChild<gtk::Image>,
         #[template_child(id = "overview-product-row-suffix")]
         pub suffix_box: TemplateChild<gtk::Box>,
+        #[template_child(id = "overview-product-row-compare-check")]
+        pub compare_check: TemplateChild<gtk::CheckButton>,
 
         #[property(get, set)]
         pub name: RefCell<String>,
@@ -24,9 +32,13 @@ mod imp {
         #[property(get, set)]
         pub logo: RefCell<String>,
         #[property(get, set)]
+        pub initials: RefCell<String>,
+        #[property(get, set)]
         pub country: RefCell<Option<String>>,
         #[property(get, construct_only)]
         pub index: Cell<u32>,
+        #[property(get, set)]
+        pub compare_mode: Cell<bool>,
     }
 
     #[glib::object_subclass]
@@ -45,7 +57,23 @@ mod imp {
     }
 
     #[glib::derived_properties]
-    impl ObjectImpl for OverviewProductRow {}
+    impl ObjectImpl for OverviewProductRow {
+        fn signals() -> &'static [Signal] {
+            static SIGNALS: OnceLock<[Signal; 1]> = OnceLock::new();
+            SIGNALS.get_or_init(|| {
+                [
+                    glib::subclass::Signal::builder(COMPARE_TOGGLED_SIGNAL)
+                        .param_types([bool::static_type()])
+                        .build()
+                ]
+            })
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+            self.obj().setup_compare_check();
+        }
+    }
     impl WidgetImpl for OverviewProductRow {}
     impl ListBoxRowImpl for OverviewProductRow {}
     impl ActionRowImpl for OverviewProductRow {}
@@ -73,13 +101,61 @@ impl OverviewProductRow {
         let escaped_name = glib::markup_escape_text(product.name);
         let escaped_summary = glib::markup_escape_text(product.summary);
 
-        let this = Self::new(&escaped_name, &escaped_summary, product.logo, index);
+        let this = Self::new(&escaped_name, &escaped_summary, product.logo.unwrap_or_default(), index);
         if let Some(country) = product.country {
             this.set_property("country", country.slug());
         }
 
         this.imp().suffix_box.set_visible(product.country.is_some());
+        this.set_initials(product.initials());
+        this.set_has_logo(product.has_logo());
         this
     }
 
+    /// `markup` must already be escaped, with only the highlight tags left
+    /// unescaped, since it is shown as the row title.
+    pub fn set_highlighted_name(&self, markup: &str) {
+        if self.name() != markup {
+            self.set_name(markup);
+        }
+    }
+
+    fn setup_compare_check(&self) {
+        let this_weak = self.downgrade();
+        self.imp().compare_check.connect_toggled(move |check| {
+            if let Some(this) = this_weak.upgrade() {
+                this.emit_by_name::<()>(COMPARE_TOGGLED_SIGNAL, &[&check.is_active()]);
+            }
+        });
+    }
+
+    /// Only emits `compare-toggled` when the value actually changes.
+    pub fn set_compared(&self, compared: bool) {
+        self.imp().compare_check.set_active(compared);
+    }
+
+    pub fn connect_compare_toggled<F>(&self, callback: F) -> glib::SignalHandlerId
+    where
+        F: Fn(&Self, bool) + 'static
+    {
+        self.connect_local(COMPARE_TOGGLED_SIGNAL, false, move |values| {
+            let this = values[0].get::<Self>().unwrap();
+            let compared = values[1].get::<bool>().unwrap();
+            callback(&this, compared);
+            None
+        })
+    }
+
+    fn set_has_logo(&self, has_logo: bool) {
+        let imp = self.imp();
+        imp.logo_image.set_visible(has_logo);
+        imp.initials_label.set_visible(!has_logo);
+
+        if has_logo {
+            self.remove_css_class("no-logo");
+        } else {
+            self.add_css_class("no-logo");
+        }
+    }
+
 }
\ No newline at end of file
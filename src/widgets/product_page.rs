@@ -8,6 +8,10 @@ use std::collections::HashMap;
 use glib::subclass::Signal;
 
 const ROW_ACTIVATED_SIGNAL: &str = "row-activated";
+const ROW_COPY_REQUESTED_SIGNAL: &str = "row-copy-requested";
+const OPEN_ALL_WEBSITES_SIGNAL: &str = "open-all-websites";
+const FAVORITE_TOGGLED_SIGNAL: &str = "favorite-toggled";
+const SHARE_REQUESTED_SIGNAL: &str = "share-requested";
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, glib::Enum)]
 #[enum_type(name = "ProductRowType")]
@@ -32,15 +36,35 @@ mod imp {
         pub country_list_box: TemplateChild<gtk::ListBox>,
         #[template_child(id = "product-page-categories-group")]
         pub categories_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child(id = "product-page-logo")]
+        pub logo_image: TemplateChild<gtk::Image>,
+        #[template_child(id = "product-page-initials")]
+        pub initials_label: TemplateChild<gtk::Label>,
+        #[template_child(id = "product-page-open-all-websites-button")]
+        pub open_all_websites_button: TemplateChild<gtk::Button>,
+        #[template_child(id = "product-page-favorite-button")]
+        pub favorite_button: TemplateChild<gtk::ToggleButton>,
+        #[template_child(id = "product-page-share-button")]
+        pub share_button: TemplateChild<gtk::Button>,
+        #[template_child(id = "product-page-summary")]
+        pub summary_label: TemplateChild<gtk::Label>,
+        #[template_child(id = "product-page-category-chips")]
+        pub category_chips_box: TemplateChild<gtk::FlowBox>,
 
         #[property(get, set)]
         pub index: Cell<u32>,
         #[property(get, set)]
         pub logo: RefCell<Option<String>>,
         #[property(get, set)]
+        pub initials: RefCell<String>,
+        #[property(get, set)]
         pub name: RefCell<String>,
         #[property(get, set)]
+        pub summary: RefCell<String>,
+        #[property(get, set)]
         pub description: RefCell<String>,
+        #[property(get, set)]
+        pub category_chips: Cell<bool>,
 
         pub rows_by_type: RefCell<HashMap<ProductRowType, Vec<ProductRow>>>,
     }
@@ -57,10 +81,20 @@ mod imp {
                 websites_group: TemplateChild::default(),
                 categories_group: TemplateChild::default(),
                 country_list_box: TemplateChild::default(),
+                logo_image: TemplateChild::default(),
+                initials_label: TemplateChild::default(),
+                open_all_websites_button: TemplateChild::default(),
+                favorite_button: TemplateChild::default(),
+                share_button: TemplateChild::default(),
+                summary_label: TemplateChild::default(),
+                category_chips_box: TemplateChild::default(),
                 index: Cell::new(0),
                 logo: RefCell::new(None),
+                initials: RefCell::new(String::new()),
                 name: RefCell::new(String::new()),
+                summary: RefCell::new(String::new()),
                 description: RefCell::new(String::new()),
+                category_chips: Cell::new(false),
             }
         }
     }
@@ -84,15 +118,31 @@ mod imp {
     #[glib::derived_properties]
     impl ObjectImpl for ProductPage {
         fn signals() -> &'static [Signal] {
-            static SIGNALS: OnceLock<[Signal; 1]> = OnceLock::new();
+            static SIGNALS: OnceLock<[Signal; 5]> = OnceLock::new();
             SIGNALS.get_or_init(|| {
                 [
                     glib::subclass::Signal::builder(ROW_ACTIVATED_SIGNAL)
                         .param_types([ProductRow::static_type(), ProductRowType::static_type()])
-                        .build()
+                        .build(),
+                    glib::subclass::Signal::builder(ROW_COPY_REQUESTED_SIGNAL)
+                        .param_types([ProductRow::static_type(), ProductRowType::static_type()])
+                        .build(),
+                    glib::subclass::Signal::builder(OPEN_ALL_WEBSITES_SIGNAL).build(),
+                    glib::subclass::Signal::builder(FAVORITE_TOGGLED_SIGNAL)
+                        .param_types([bool::static_type()])
+                        .build(),
+                    glib::subclass::Signal::builder(SHARE_REQUESTED_SIGNAL).build()
                 ]
             })
         }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+            self.obj().setup_open_all_websites_button();
+            self.obj().setup_favorite_button();
+            self.obj().setup_share_button();
+            self.obj().setup_category_chips();
+        }
     }
 
     impl WidgetImpl for ProductPage {}
@@ -106,12 +156,109 @@ glib::wrapper! {
 }
 
 impl ProductPage {
+    fn setup_open_all_websites_button(&self) {
+        let this_weak = self.downgrade();
+        self.imp().open_all_websites_button.connect_clicked(move |_| {
+            if let Some(this) = this_weak.upgrade() {
+                this.emit_by_name::<()>(OPEN_ALL_WEBSITES_SIGNAL, &[]);
+            }
+        });
+    }
+
+    fn setup_favorite_button(&self) {
+        let this_weak = self.downgrade();
+        self.imp().favorite_button.connect_clicked(move |button| {
+            if let Some(this) = this_weak.upgrade() {
+                let favorite = button.is_active();
+                this.update_favorite_button(favorite);
+                this.emit_by_name::<()>(FAVORITE_TOGGLED_SIGNAL, &[&favorite]);
+            }
+        });
+    }
+
+    fn setup_share_button(&self) {
+        let this_weak = self.downgrade();
+        self.imp().share_button.connect_clicked(move |_| {
+            if let Some(this) = this_weak.upgrade() {
+                this.emit_by_name::<()>(SHARE_REQUESTED_SIGNAL, &[]);
+            }
+        });
+    }
+
+    fn setup_category_chips(&self) {
+        self.connect_category_chips_notify(|this| {
+            this.update_category_presentation();
+        });
+    }
+
+    fn update_category_presentation(&self) {
+        let imp = self.imp();
+        let category_chips = self.category_chips();
+        imp.category_chips_box.set_visible(category_chips);
+
+        if let Some(rows) = imp.rows_by_type.borrow().get(&ProductRowType::Category) {
+            for row in rows {
+                row.set_visible(!category_chips);
+            }
+        }
+    }
+
+    /// Chips stand in for the category rows and activate them, so both
+    /// layouts go through the same `row-activated` emission.
+    fn add_category_chip(&self, row: &ProductRow) {
+        let label = gtk::Label::builder()
+            .label(row.title())
+            .use_markup(true)
+            .build();
+        let chip = gtk::Button::builder()
+            .child(&label)
+            .css_classes(["pill", "product-page-category-chip"])
+            .build();
+
+        let this_weak = self.downgrade();
+        let row_weak = row.downgrade();
+        chip.connect_clicked(move |_| {
+            if
+                let Some(this) = this_weak.upgrade()
+                && let Some(row) = row_weak.upgrade()
+            {
+                this.emit_by_name::<()>(ROW_ACTIVATED_SIGNAL, &[&row, &ProductRowType::Category]);
+            }
+        });
+
+        self.imp().category_chips_box.append(&chip);
+    }
+
+    fn update_favorite_button(&self, favorite: bool) {
+        let button = &self.imp().favorite_button;
+        if favorite {
+            button.set_icon_name("starred-symbolic");
+            button.set_tooltip_text(Some("Remove from Favorites"));
+        } else {
+            button.set_icon_name("non-starred-symbolic");
+            button.set_tooltip_text(Some("Add to Favorites"));
+        }
+    }
+
+    pub fn set_favorite(&self, favorite: bool) {
+        self.imp().favorite_button.set_active(favorite);
+        self.update_favorite_button(favorite);
+    }
+
+    pub fn set_open_all_websites_visible(&self, visible: bool) {
+        self.imp().open_all_websites_button.set_visible(visible);
+    }
+
     fn add_row_to_container(&self, row: &ProductRow, row_type: ProductRowType) {
         let imp = self.imp();
         match row_type {
             ProductRowType::Country => imp.country_list_box.append(row),
             ProductRowType::Website => imp.websites_group.add(row),
-            ProductRowType::Category => imp.categories_group.add(row),
+            ProductRowType::Category => {
+                imp.categories_group.add(row);
+                row.set_visible(!self.category_chips());
+                self.add_category_chip(row);
+            }
         }
     }
 
@@ -133,6 +280,54 @@ impl ProductPage {
         })
     }
 
+    fn setup_row_copy_request(&self, row: &ProductRow, row_type: ProductRowType) -> glib::SignalHandlerId {
+        let this_weak = self.downgrade();
+        row.connect_copy_requested(move |requested_row| {
+            if let Some(this) = this_weak.upgrade() {
+                this.emit_by_name::<()>(ROW_COPY_REQUESTED_SIGNAL, &[&requested_row, &row_type]);
+            }
+        })
+    }
+
+    pub fn set_product_logo(&self, logo: Option<&str>, initials: &str) {
+        let imp = self.imp();
+        let has_logo = logo.is_some();
+
+        self.set_logo(logo);
+        self.set_initials(initials);
+        imp.logo_image.set_visible(has_logo);
+        imp.initials_label.set_visible(!has_logo);
+
+        if has_logo {
+            self.remove_css_class("no-logo");
+        } else {
+            self.add_css_class("no-logo");
+        }
+    }
+
+    /// Shows the summary as a lead paragraph, unless the description already
+    /// starts with it.
+    pub fn set_product_summary(&self, summary: &str, description: &str) {
+        let summary = if Self::is_summary_redundant(summary, description) { "" } else { summary };
+        self.set_summary(summary);
+        self.imp().summary_label.set_visible(!summary.is_empty());
+    }
+
+    /// Compares word by word, ignoring case and trailing punctuation, so a
+    /// summary that is just the opening of the description counts as a prefix.
+    pub fn is_summary_redundant(summary: &str, description: &str) -> bool {
+        let normalize = |text: &str| {
+            text.split_whitespace()
+                .map(|word| word.trim_end_matches(['.', ',', ';', ':', '!']).to_lowercase())
+                .filter(|word| !word.is_empty())
+                .collect::<Vec<_>>()
+        };
+
+        let summary_words = normalize(summary);
+        let description_words = normalize(description);
+        summary_words.is_empty() || description_words.starts_with(&summary_words)
+    }
+
     pub fn remove_all_rows(&self) {
         let imp = self.imp();
         let mut rows = imp.rows_by_type.borrow_mut();
@@ -142,6 +337,8 @@ impl ProductPage {
                 self.remove_row_from_container(&row, *row_type);
             }
         }
+
+        imp.category_chips_box.remove_all();
     }
 
     pub fn append_row(&self, row: ProductRow, row_type: ProductRowType) {
@@ -151,6 +348,7 @@ impl ProductPage {
 
         self.add_row_to_container(&row, row_type);
         self.setup_row_activation(&row, row_type);
+        self.setup_row_copy_request(&row, row_type);
         rows.push(row);
     }
 
@@ -166,4 +364,92 @@ impl ProductPage {
             None
         })
     }
+
+    pub fn connect_row_copy_requested<F>(&self, callback: F) -> glib::SignalHandlerId
+    where
+        F: Fn(&Self, &ProductRow, ProductRowType) + 'static
+    {
+        self.connect_local(ROW_COPY_REQUESTED_SIGNAL, false, move |values| {
+            let this = values[0].get::<Self>().unwrap();
+            let row = values[1].get::<&ProductRow>().unwrap();
+            let row_type = values[2].get::<ProductRowType>().unwrap();
+            callback(&this, row, row_type);
+            None
+        })
+    }
+
+    pub fn connect_open_all_websites<F>(&self, callback: F) -> glib::SignalHandlerId
+    where
+        F: Fn(&Self) + 'static
+    {
+        self.connect_local(OPEN_ALL_WEBSITES_SIGNAL, false, move |values| {
+            let this = values[0].get::<Self>().unwrap();
+            callback(&this);
+            None
+        })
+    }
+
+    pub fn connect_favorite_toggled<F>(&self, callback: F) -> glib::SignalHandlerId
+    where
+        F: Fn(&Self, bool) + 'static
+    {
+        self.connect_local(FAVORITE_TOGGLED_SIGNAL, false, move |values| {
+            let this = values[0].get::<Self>().unwrap();
+            let favorite = values[1].get::<bool>().unwrap();
+            callback(&this, favorite);
+            None
+        })
+    }
+
+    pub fn connect_share_requested<F>(&self, callback: F) -> glib::SignalHandlerId
+    where
+        F: Fn(&Self) + 'static
+    {
+        self.connect_local(SHARE_REQUESTED_SIGNAL, false, move |values| {
+            let this = values[0].get::<Self>().unwrap();
+            callback(&this);
+            None
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_opening_the_description_is_redundant() {
+        assert!(ProductPage::is_summary_redundant(
+            "Web browser",
+            "Web browser with a built-in mail client."
+        ));
+        assert!(ProductPage::is_summary_redundant("Online photo editor.", "Online photo editor."));
+    }
+
+    #[test]
+    fn summary_saying_something_else_is_shown() {
+        assert!(!ProductPage::is_summary_redundant(
+            "End-to-end encrypted email.",
+            "Encrypted email with single sign-on."
+        ));
+        assert!(!ProductPage::is_summary_redundant(
+            "Web browser with tabs.",
+            "Web browser."
+        ));
+    }
+
+    #[test]
+    fn case_and_trailing_punctuation_are_ignored() {
+        assert!(ProductPage::is_summary_redundant(
+            "ALL-IN-ONE self-hosted cloud.",
+            "All-in-one self-hosted cloud, with a Node.js client."
+        ));
+        assert!(!ProductPage::is_summary_redundant("Web-browser.", "Web browser."));
+    }
+
+    #[test]
+    fn empty_summary_is_redundant() {
+        assert!(ProductPage::is_summary_redundant("", "Web browser."));
+        assert!(ProductPage::is_summary_redundant(" ... ", "Web browser."));
+    }
 }
\ No newline at end of file
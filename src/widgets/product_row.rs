@@ -1,7 +1,13 @@
 use super::super::prelude::*;
 use super::super::models::{Category, Country};
 
-use std::cell::{Cell, RefCell};
+use std::cell::{Cell, OnceCell, RefCell};
+use std::sync::OnceLock;
+use glib::subclass::Signal;
+
+const COPY_REQUESTED_SIGNAL: &str = "copy-requested";
+const ACTION_GROUP_NAME: &str = "row";
+const COPY_LINK_ACTION: &str = "copy-link";
 
 mod imp {
     use super::*;
@@ -23,6 +29,8 @@ mod imp {
         pub index: Cell<u32>,
         #[property(get, set)]
         pub feature_subtitle: Cell<bool>,
+
+        pub context_menu: OnceCell<gtk::PopoverMenu>,
     }
 
     #[glib::object_subclass]
@@ -42,10 +50,25 @@ mod imp {
 
     #[glib::derived_properties]
     impl ObjectImpl for ProductRow {
+        fn signals() -> &'static [Signal] {
+            static SIGNALS: OnceLock<[Signal; 1]> = OnceLock::new();
+            SIGNALS.get_or_init(|| {
+                [
+                    glib::subclass::Signal::builder(COPY_REQUESTED_SIGNAL).build()
+                ]
+            })
+        }
+
         fn constructed(&self) {
             self.parent_constructed();
             self.obj().setup_classes();
         }
+
+        fn dispose(&self) {
+            if let Some(context_menu) = self.context_menu.get() {
+                context_menu.unparent();
+            }
+        }
     }
 
     impl WidgetImpl for ProductRow {}
@@ -120,12 +143,85 @@ impl ProductRow {
     pub fn from_website(caption: &str, url: &str, index: usize) -> Self {
         let this = Self::new(caption, Some(url), None, index);
         this.set_feature_subtitle(true);
+        this.setup_copy_request();
         this
     }
 
+    fn setup_copy_request(&self) {
+        let copy_button = gtk::Button::builder()
+            .icon_name("edit-copy-symbolic")
+            .tooltip_text("Copy Link")
+            .valign(gtk::Align::Center)
+            .css_classes(["flat"])
+            .build();
+
+        let this_weak = self.downgrade();
+        copy_button.connect_clicked(move |_| {
+            if let Some(this) = this_weak.upgrade() {
+                this.emit_by_name::<()>(COPY_REQUESTED_SIGNAL, &[]);
+            }
+        });
+        self.add_suffix(&copy_button);
+
+        self.setup_context_menu();
+    }
+
+    fn setup_context_menu(&self) {
+        let copy_link_action = gtk::gio::SimpleAction::new(COPY_LINK_ACTION, None);
+        let this_weak = self.downgrade();
+        copy_link_action.connect_activate(move |_, _| {
+            if let Some(this) = this_weak.upgrade() {
+                this.emit_by_name::<()>(COPY_REQUESTED_SIGNAL, &[]);
+            }
+        });
+        let action_group = gtk::gio::SimpleActionGroup::new();
+        action_group.add_action(&copy_link_action);
+        self.insert_action_group(ACTION_GROUP_NAME, Some(&action_group));
+
+        let menu = gtk::gio::Menu::new();
+        menu.append(Some("Copy Link"), Some(&format!("{ACTION_GROUP_NAME}.{COPY_LINK_ACTION}")));
+        let context_menu = gtk::PopoverMenu::from_model(Some(&menu));
+        context_menu.set_parent(self);
+        context_menu.set_has_arrow(false);
+        context_menu.set_halign(gtk::Align::Start);
+        self.imp().context_menu.set(context_menu).ok().expect("context menu set once");
+
+        let secondary_click = gtk::GestureClick::builder()
+            .button(gtk::gdk::BUTTON_SECONDARY)
+            .build();
+        let this_weak = self.downgrade();
+        secondary_click.connect_pressed(move |gesture, _, x, y| {
+            if let Some(this) = this_weak.upgrade() {
+                gesture.set_state(gtk::EventSequenceState::Claimed);
+                this.popup_context_menu(x, y);
+            }
+        });
+        self.add_controller(secondary_click);
+    }
+
+    fn popup_context_menu(&self, x: f64, y: f64) {
+        if let Some(context_menu) = self.imp().context_menu.get() {
+            let pointing_to = gtk::gdk::Rectangle::new(x as i32, y as i32, 1, 1);
+            context_menu.set_pointing_to(Some(&pointing_to));
+            context_menu.popup();
+        }
+    }
+
+    pub fn connect_copy_requested<F>(&self, callback: F) -> glib::SignalHandlerId
+    where
+        F: Fn(&Self) + 'static
+    {
+        self.connect_local(COPY_REQUESTED_SIGNAL, false, move |values| {
+            let this = values[0].get::<Self>().unwrap();
+            callback(&this);
+            None
+        })
+    }
+
     pub fn from_country(country: Country) -> Self {
         let this = Self::new("Country", Some(country.display_name()), Some(country.slug()), country as usize);
         this.set_feature_subtitle(true);
+        this.set_tooltip_text(Some(&country.details()));
         this
     }
 
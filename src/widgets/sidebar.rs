@@ -2,6 +2,20 @@ use crate::prelude::*;
 use super::sidebar_primary_list::SidebarPrimaryList;
 use super::sidebar_category_list::SidebarCategoryList;
 
+/// Search text and country filter of the sidebar, kept so they can be put back
+/// after navigating away clears them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SidebarFilters {
+    pub search_text: String,
+    pub country_indices: Vec<usize>,
+}
+
+impl SidebarFilters {
+    pub const fn is_empty(&self) -> bool {
+        self.search_text.is_empty() && self.country_indices.is_empty()
+    }
+}
+
 mod implementation {
     use super::*;
 
@@ -59,6 +73,18 @@ impl Sidebar {
         self.primary_list().deactivate_rows();
     }
 
+    pub fn filters(&self) -> SidebarFilters {
+        SidebarFilters {
+            search_text: self.primary_list().search_row().search_text().to_string(),
+            country_indices: self.primary_list().country_row().selected_country_indices(),
+        }
+    }
+
+    pub fn restore_filters(&self, filters: &SidebarFilters) {
+        self.primary_list().country_row().select_items_by_indices(&filters.country_indices);
+        self.primary_list().search_row().set_search_text(&filters.search_text);
+    }
+
     pub fn clear_changes(&self) {
         self.primary_list().search_row().clear_search();
         self.primary_list().country_row().select_default_item();
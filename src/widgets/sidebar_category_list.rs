@@ -10,6 +10,8 @@ mod imp {
     #[derive(Debug, Default, gtk::CompositeTemplate)]
     #[template(resource = "/pt/timarques/european_choice/sidebar_category_list.ui")]
     pub struct SidebarCategoryList {
+        #[template_child(id = "sidebar-category-list-scrolled-window")]
+        pub scrolled_window: TemplateChild<gtk::ScrolledWindow>,
         #[template_child(id = "sidebar-category-list-box")]
         pub list_box: TemplateChild<gtk::ListBox>,
 
@@ -60,10 +62,52 @@ impl SidebarCategoryList {
     }
 
     pub fn select_row_by_index(&self, index: usize) -> bool {
-        self.imp().rows.borrow().get(&index).is_some_and(|row| {
+        let selected = self.imp().rows.borrow().get(&index).is_some_and(|row| {
             self.imp().list_box.select_row(Some(row));
             true
-        })
+        });
+
+        if selected {
+            self.ensure_row_visible(index);
+        }
+        selected
+    }
+
+    /// The row for a category index, unless it is filtered out.
+    pub fn visible_row(&self, index: usize) -> Option<SidebarRow> {
+        self.rows().get(&index).filter(|row| row.is_visible()).cloned()
+    }
+
+    /// Scrolls the list just enough for the row to be fully shown, without
+    /// moving focus. Returns false when the row is filtered out or not laid out.
+    pub fn ensure_row_visible(&self, index: usize) -> bool {
+        let imp = self.imp();
+        let Some(row) = self.visible_row(index) else {
+            return false;
+        };
+        let Some(bounds) = row.compute_bounds(&*imp.list_box) else {
+            return false;
+        };
+
+        let adjustment = imp.scrolled_window.vadjustment();
+        let top = f64::from(bounds.y());
+        let bottom = top + f64::from(bounds.height());
+        if let Some(value) = Self::scroll_value_to_reveal(top, bottom, adjustment.value(), adjustment.page_size()) {
+            adjustment.set_value(value);
+        }
+        true
+    }
+
+    /// New scroll value that brings `top..bottom` into the page, or `None` when
+    /// it is already fully visible. Rows taller than the page align to the top.
+    pub fn scroll_value_to_reveal(top: f64, bottom: f64, value: f64, page_size: f64) -> Option<f64> {
+        if top < value || bottom - top > page_size {
+            Some(top)
+        } else if bottom > value + page_size {
+            Some(bottom - page_size)
+        } else {
+            None
+        }
     }
 
     pub fn select_first(&self) -> bool {
@@ -77,6 +121,12 @@ impl SidebarCategoryList {
         self.imp().rows.borrow()
     }
 
+    pub fn set_category_colors(&self, enabled: bool) {
+        for row in self.rows().values() {
+            row.set_category_color(enabled);
+        }
+    }
+
     pub fn show_all_rows(&self) {
         let rows = self.rows();
 
@@ -113,4 +163,32 @@ impl SidebarCategoryList {
             }
         });
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE_SIZE: f64 = 100.0;
+
+    #[test]
+    fn rows_above_the_page_align_to_the_top() {
+        assert_eq!(SidebarCategoryList::scroll_value_to_reveal(20.0, 50.0, 40.0, PAGE_SIZE), Some(20.0));
+    }
+
+    #[test]
+    fn rows_below_the_page_align_to_the_bottom() {
+        assert_eq!(SidebarCategoryList::scroll_value_to_reveal(130.0, 160.0, 40.0, PAGE_SIZE), Some(60.0));
+    }
+
+    #[test]
+    fn visible_rows_do_not_scroll() {
+        assert_eq!(SidebarCategoryList::scroll_value_to_reveal(40.0, 70.0, 40.0, PAGE_SIZE), None);
+        assert_eq!(SidebarCategoryList::scroll_value_to_reveal(110.0, 140.0, 40.0, PAGE_SIZE), None);
+    }
+
+    #[test]
+    fn rows_taller_than_the_page_align_to_the_top() {
+        assert_eq!(SidebarCategoryList::scroll_value_to_reveal(60.0, 200.0, 40.0, PAGE_SIZE), Some(60.0));
+        assert_eq!(SidebarCategoryList::scroll_value_to_reveal(0.0, 150.0, 40.0, PAGE_SIZE), Some(0.0));
+    }
+}
@@ -21,6 +21,10 @@ mod implementation {
         #[property(get, set)]
         pub caption_visible: Cell<bool>,
         #[property(get, set)]
+        pub checkable: Cell<bool>,
+        #[property(get, set)]
+        pub checked: Cell<bool>,
+        #[property(get, set)]
         pub index: Cell<u32>,
     }
 
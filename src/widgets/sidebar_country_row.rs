@@ -1,13 +1,15 @@
 use super::super::prelude::*;
 use super::sidebar_country_item::SidebarCountryItem;
+use super::super::models::Country;
 
 use std::cell::Cell;
 use std::sync::OnceLock;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::cell::RefCell;
 
 const DEFAULT_INDEX: u32 = 0;
 const STATE_CHANGED_SIGNAL: &str = "state-changed";
+const SELECTION_CHANGED_SIGNAL: &str = "selection-changed";
 
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, glib::Enum)]
 #[enum_type(name = "SidebarCountryRowState")]
@@ -30,7 +32,10 @@ mod imp {
         pub state: Cell<SidebarCountryRowState>,
         pub list_store: gtk::gio::ListStore,
         pub factory: gtk::SignalListItemFactory,
-        pub map: RefCell<HashMap<usize, u32>>
+        pub map: RefCell<HashMap<usize, u32>>,
+        pub multi_select: Cell<bool>,
+        pub selected: RefCell<HashSet<usize>>,
+        pub updating: Cell<bool>,
     }
 
     impl Default for SidebarCountryRow {
@@ -44,7 +49,10 @@ mod imp {
                 list_store: gtk::gio::ListStore::new::<SidebarCountryItem>(),
                 factory: gtk::SignalListItemFactory::new(),
                 default_item,
-                map: RefCell::new(HashMap::new())
+                map: RefCell::new(HashMap::new()),
+                multi_select: Cell::new(false),
+                selected: RefCell::new(HashSet::new()),
+                updating: Cell::new(false),
             }
         }
     }
@@ -75,12 +83,13 @@ mod imp {
         }
 
         fn signals() -> &'static [glib::subclass::Signal] {
-            static SIGNALS: OnceLock<[glib::subclass::Signal; 1]> = OnceLock::new();
+            static SIGNALS: OnceLock<[glib::subclass::Signal; 2]> = OnceLock::new();
             SIGNALS.get_or_init(|| {
                 [
                     glib::subclass::Signal::builder(STATE_CHANGED_SIGNAL)
                         .param_types([<SidebarCountryRowState>::static_type()])
                         .build(),
+                    glib::subclass::Signal::builder(SELECTION_CHANGED_SIGNAL).build(),
                 ]
             })
         }
@@ -106,15 +115,11 @@ impl SidebarCountryRow {
 
         let this_weak = self.downgrade();
         imp.dropdown.connect_selected_item_notify(move |_| {
-            if let Some(this) = this_weak.upgrade()
+            if
+                let Some(this) = this_weak.upgrade()
+                && !this.imp().updating.get()
             {
-                let new_state = if this.is_selected_default() {
-                    SidebarCountryRowState::Inactive
-                } else {
-                    SidebarCountryRowState::Active
-                };
-
-                this.set_state(new_state);
+                this.handle_selected_item_changed();
             }
         });
     }
@@ -138,6 +143,8 @@ impl SidebarCountryRow {
                     widget_child.set_index(data_item.index());
                     widget_child.set_caption(data_item.caption());
                     widget_child.set_caption_visible(false);
+                    widget_child.set_checkable(data_item.checkable());
+                    widget_child.set_checked(data_item.checked());
                     if let Some(flag) = data_item.flag() {
                         widget_child.set_flag(flag);
                     }
@@ -164,8 +171,131 @@ impl SidebarCountryRow {
         });
     }
 
-    pub fn is_selected_default(&self) -> bool {
-        self.imp().dropdown.selected() == DEFAULT_INDEX
+    fn handle_selected_item_changed(&self) {
+        let item_index = self.selected_item().map(|item| item.index() as usize);
+
+        if self.is_multi_select() {
+            Self::toggle_selection(&mut self.imp().selected.borrow_mut(), item_index);
+            self.with_updating(|| self.imp().dropdown.set_selected(DEFAULT_INDEX));
+        } else {
+            let mut selected = self.imp().selected.borrow_mut();
+            selected.clear();
+            selected.extend(item_index);
+        }
+
+        self.finish_selection_change();
+    }
+
+    /// Picking a country in multi-select mode flips it in or out of the set,
+    /// while picking the default item clears it.
+    fn toggle_selection(selected: &mut HashSet<usize>, index: Option<usize>) {
+        match index {
+            Some(index) => {
+                if !selected.remove(&index) {
+                    selected.insert(index);
+                }
+            }
+            None => selected.clear(),
+        }
+    }
+
+    fn finish_selection_change(&self) {
+        self.refresh_items();
+
+        let new_state = if self.has_selection() {
+            SidebarCountryRowState::Active
+        } else {
+            SidebarCountryRowState::Inactive
+        };
+
+        self.set_state(new_state);
+        self.emit_by_name::<()>(SELECTION_CHANGED_SIGNAL, &[]);
+    }
+
+    fn refresh_items(&self) {
+        let imp = self.imp();
+        let multi_select = self.is_multi_select();
+        let selected = imp.selected.borrow();
+
+        let default_label = if multi_select && !selected.is_empty() {
+            format!("{count} Countries", count = selected.len())
+        } else {
+            String::from("All")
+        };
+        imp.default_item.set_label(default_label);
+
+        for position in 1..imp.list_store.n_items() {
+            if let Some(item) = imp.list_store.item(position).and_downcast::<SidebarCountryItem>() {
+                item.set_checkable(multi_select);
+                item.set_checked(multi_select && selected.contains(&(item.index() as usize)));
+            }
+        }
+        drop(selected);
+
+        if multi_select {
+            self.rebind_items();
+        }
+    }
+
+    fn rebind_items(&self) {
+        let list_store = &self.imp().list_store;
+        let items_count = list_store.n_items();
+        self.with_updating(|| list_store.items_changed(0, items_count, items_count));
+    }
+
+    fn with_updating<F: FnOnce()>(&self, action: F) {
+        let updating = &self.imp().updating;
+        let was_updating = updating.replace(true);
+        action();
+        updating.set(was_updating);
+    }
+
+    pub fn has_selection(&self) -> bool {
+        !self.imp().selected.borrow().is_empty()
+    }
+
+    pub fn is_multi_select(&self) -> bool {
+        self.imp().multi_select.get()
+    }
+
+    pub fn set_multi_select(&self, multi_select: bool) {
+        let imp = self.imp();
+        if imp.multi_select.replace(multi_select) == multi_select {
+            return;
+        }
+
+        imp.selected.borrow_mut().clear();
+        self.with_updating(|| imp.dropdown.set_selected(DEFAULT_INDEX));
+        self.finish_selection_change();
+        self.rebind_items();
+    }
+
+    pub fn selected_indices(&self) -> HashSet<usize> {
+        self.imp().selected.borrow().clone()
+    }
+
+    /// Selected countries in catalog order.
+    pub fn selected_countries(&self) -> Vec<Country> {
+        let mut countries = self
+            .selected_indices()
+            .into_iter()
+            .filter_map(Country::from_index)
+            .collect::<Vec<_>>();
+        countries.sort_by_key(|country| *country as usize);
+        countries
+    }
+
+    /// The country being filtered on, or `None` for "All Countries" and for
+    /// multi-select filters spanning several countries.
+    pub fn selected_country(&self) -> Option<Country> {
+        Self::resolve_country(&self.selected_indices())
+    }
+
+    pub fn resolve_country(indices: &HashSet<usize>) -> Option<Country> {
+        match indices.len() {
+            1 => indices.iter().next().copied().and_then(Country::from_index),
+            _ => None,
+        }
     }
 
     pub fn set_state(&self, new_state: SidebarCountryRowState) -> bool {
@@ -174,7 +304,7 @@ impl SidebarCountryRow {
             return false;
         }
 
-        match (new_state, self.is_selected_default()) {
+        match (new_state, !self.has_selection()) {
             (SidebarCountryRowState::Active, true) => {
                 self.imp().dropdown.emit_activate();
                 return false;
@@ -188,16 +318,23 @@ impl SidebarCountryRow {
         true
     }
 
+    pub fn popup(&self) {
+        self.imp().dropdown.emit_activate();
+    }
+
     pub fn add_item(&self, item: &SidebarCountryItem) {
         let imp = self.imp();
         let items_count = imp.list_store.n_items();
         imp.list_store.append(item);
 
         if items_count == 1 {
-            imp.dropdown.set_selected(items_count);
-            imp.dropdown.set_selected(DEFAULT_INDEX);
+            self.with_updating(|| {
+                imp.dropdown.set_selected(items_count);
+                imp.dropdown.set_selected(DEFAULT_INDEX);
+            });
         }
 
+        item.set_checkable(self.is_multi_select());
         imp.map.borrow_mut().insert(item.index() as usize, items_count);
     }
 
@@ -210,19 +347,75 @@ impl SidebarCountryRow {
 
     pub fn select_item_by_index(&self, index: usize) -> bool {
         let imp = self.imp();
-        let dropdown = &imp.dropdown;
-        imp.map
-            .borrow()
-            .get(&index)
+        let Some(position) = imp.map.borrow().get(&index).copied() else {
+            return false;
+        };
+
+        if self.is_multi_select() {
+            let mut selected = imp.selected.borrow_mut();
+            selected.clear();
+            selected.insert(index);
+            drop(selected);
+            self.finish_selection_change();
+        } else {
+            imp.dropdown.set_selected(position);
+        }
+
+        true
+    }
+
+    /// Returns false when the country has no item, e.g. because none of its
+    /// products made it into the catalog.
+    pub fn select_country(&self, country: Country) -> bool {
+        self.select_item_by_index(country as usize)
+    }
+
+    pub fn has_country(&self, country: Country) -> bool {
+        self.imp().map.borrow().contains_key(&(country as usize))
+    }
+
+    /// Country indices currently filtered on, whichever selection mode is active.
+    pub fn selected_country_indices(&self) -> Vec<usize> {
+        if self.is_multi_select() {
+            let mut indices = self.selected_indices().into_iter().collect::<Vec<_>>();
+            indices.sort_unstable();
+            indices
+        } else {
+            self.selected_item()
+                .map(|item| vec![item.index() as usize])
+                .unwrap_or_default()
+        }
+    }
+
+    pub fn select_items_by_indices(&self, indices: &[usize]) -> bool {
+        let Some(&first_index) = indices.first() else {
+            self.select_default_item();
+            return true;
+        };
+
+        if !self.is_multi_select() {
+            return self.select_item_by_index(first_index);
+        }
+
+        let imp = self.imp();
+        let known_indices = indices
+            .iter()
             .copied()
-            .is_some_and(|position| {
-                dropdown.set_selected(position);
-                true
-            })
+            .filter(|index| imp.map.borrow().contains_key(index))
+            .collect::<HashSet<_>>();
+        let restored_all = known_indices.len() == indices.len();
+        imp.selected.replace(known_indices);
+        self.finish_selection_change();
+        restored_all
     }
 
     pub fn select_default_item(&self) {
-        self.imp().dropdown.set_selected(DEFAULT_INDEX);
+        if self.is_multi_select() {
+            self.imp().selected.borrow_mut().clear();
+            self.finish_selection_change();
+        } else {
+            self.imp().dropdown.set_selected(DEFAULT_INDEX);
+        }
     }
 
     pub fn connect_state_changed<F>(&self, callback: F) -> glib::SignalHandlerId
@@ -237,18 +430,106 @@ impl SidebarCountryRow {
         })
     }
 
-    pub fn connect_item_selected<F>(&self, callback: F) -> glib::SignalHandlerId
+    pub fn connect_selection_changed<F>(&self, callback: F) -> glib::SignalHandlerId
     where
-        F: Fn(&Self, Option<&SidebarCountryItem>) + 'static
+        F: Fn(&Self, &HashSet<usize>) + 'static
     {
-        let this_weak = self.downgrade();
-        self.imp().dropdown.connect_selected_item_notify(move |_| {
-            if
-                let Some(this) = this_weak.upgrade()
-            {
-                let item = this.selected_item();
-                callback(&this, item.as_ref());
-            }
+        self.connect_local(SELECTION_CHANGED_SIGNAL, false, move |values| {
+            let this = values[0].get::<Self>().unwrap();
+            let selected = this.selected_indices();
+            callback(&this, &selected);
+            None
         })
     }
-}
\ No newline at end of file
+
+    pub fn connect_country_changed<F>(&self, callback: F) -> glib::SignalHandlerId
+    where
+        F: Fn(&Self, Option<Country>) + 'static
+    {
+        self.connect_selection_changed(move |this, selected| {
+            callback(this, Self::resolve_country(selected));
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_catalog;
+    use crate::test_gtk;
+
+    fn populated_row() -> SidebarCountryRow {
+        let row = glib::Object::builder::<SidebarCountryRow>().build();
+        for country in test_catalog::repository().countries_with_products() {
+            row.add_item(&SidebarCountryItem::from_country(country));
+        }
+        row
+    }
+
+    #[test]
+    fn single_selection_resolves_to_its_country() {
+        let germany = Country::Germany as usize;
+        assert_eq!(SidebarCountryRow::resolve_country(&HashSet::from([germany])), Some(Country::Germany));
+    }
+
+    #[test]
+    fn no_or_several_selections_resolve_to_none() {
+        let several = HashSet::from([Country::Germany as usize, Country::Sweden as usize]);
+        assert_eq!(SidebarCountryRow::resolve_country(&HashSet::new()), None);
+        assert_eq!(SidebarCountryRow::resolve_country(&several), None);
+    }
+
+    #[test]
+    fn toggling_adds_and_removes_countries() {
+        let germany = Country::Germany as usize;
+        let sweden = Country::Sweden as usize;
+        let mut selected = HashSet::new();
+
+        SidebarCountryRow::toggle_selection(&mut selected, Some(germany));
+        SidebarCountryRow::toggle_selection(&mut selected, Some(sweden));
+        assert_eq!(selected, HashSet::from([germany, sweden]));
+
+        SidebarCountryRow::toggle_selection(&mut selected, Some(germany));
+        assert_eq!(selected, HashSet::from([sweden]));
+    }
+
+    #[test]
+    fn toggling_the_default_item_clears_the_selection() {
+        let mut selected = HashSet::from([Country::Germany as usize, Country::Sweden as usize]);
+        SidebarCountryRow::toggle_selection(&mut selected, None);
+        assert!(selected.is_empty());
+    }
+
+    #[test]
+    fn unknown_index_resolves_to_none() {
+        assert_eq!(SidebarCountryRow::resolve_country(&HashSet::from([Country::COUNT])), None);
+    }
+
+    #[test]
+    fn only_populated_countries_are_known() {
+        let known = test_gtk::run(|| {
+            let row = populated_row();
+            [Country::Germany, Country::Switzerland, Country::Sweden].map(|country| row.has_country(country))
+        });
+
+        if let Some(known) = known {
+            assert_eq!(known, [true, true, false]);
+        }
+    }
+
+    #[test]
+    fn selecting_a_populated_country_filters_on_it() {
+        let selections = test_gtk::run(|| {
+            let row = populated_row();
+            let selected_germany = row.select_country(Country::Germany);
+            let selected_sweden = row.select_country(Country::Sweden);
+            (selected_germany, selected_sweden, row.selected_country())
+        });
+
+        if let Some((selected_germany, selected_sweden, selected_country)) = selections {
+            assert!(selected_germany);
+            assert!(!selected_sweden);
+            assert_eq!(selected_country, Some(Country::Germany));
+        }
+    }
+}
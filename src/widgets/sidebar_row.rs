@@ -55,11 +55,21 @@ impl SidebarRow {
     }
 
     pub fn from_category(category: &Category, index: usize) -> Self {
-        glib::Object::builder::<Self>()
+        let this = glib::Object::builder::<Self>()
             .property("icon", category.icon)
             .property("label", category.name)
             .property("index", index as u32)
-            .build()
+            .build();
+        this.add_css_class(&category.color_class());
+        this
+    }
+
+    pub fn set_category_color(&self, enabled: bool) {
+        if enabled {
+            self.add_css_class("category-color");
+        } else {
+            self.remove_css_class("category-color");
+        }
     }
 
 }
\ No newline at end of file
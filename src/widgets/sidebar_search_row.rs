@@ -1,8 +1,9 @@
 use glib::GString;
 
 use crate::prelude::*;
+use super::global_search_popover::GlobalSearchPopover;
 use std::sync::OnceLock;
-use std::cell::Cell;
+use std::cell::{Cell, OnceCell};
 
 const STATE_CHANGED_SIGNAL: &str = "state-changed";
 const ACTIVE_CSS_CLASS: &str = "active";
@@ -26,9 +27,12 @@ mod imp {
         pub search_entry: TemplateChild<gtk::SearchEntry>,
         #[template_child(id = "sidebar-search-row-click-gesture")]
         pub click_gesture: TemplateChild<gtk::GestureClick>,
+        #[template_child(id = "sidebar-search-row-spinner")]
+        pub spinner: TemplateChild<gtk::Spinner>,
 
         pub had_content: Cell<bool>,
         pub state: Cell<SidebarSearchRowState>,
+        pub global_search_popover: OnceCell<GlobalSearchPopover>,
     }
 
     #[glib::object_subclass]
@@ -54,6 +58,13 @@ mod imp {
             let obj = self.obj();
             obj.setup_search_entry();
             obj.setup_state_changes();
+            obj.setup_global_search_popover();
+        }
+
+        fn dispose(&self) {
+            if let Some(popover) = self.global_search_popover.get() {
+                popover.unparent();
+            }
         }
 
         fn signals() -> &'static [glib::subclass::Signal] {
@@ -112,6 +123,16 @@ impl SidebarSearchRow {
         });
     }
 
+    fn setup_global_search_popover(&self) {
+        let popover = GlobalSearchPopover::new();
+        popover.set_parent(self);
+        self.imp().global_search_popover.set(popover).ok().expect("popover set once");
+    }
+
+    pub fn global_search_popover(&self) -> &GlobalSearchPopover {
+        self.imp().global_search_popover.get().unwrap()
+    }
+
     fn setup_state_changes(&self) {
         self.connect_state_changed(|this, state| {
             match state {
@@ -126,7 +147,7 @@ impl SidebarSearchRow {
         });
     }
 
-    fn set_successful_search(&self, is_successful: bool) {
+    pub fn set_successful_search(&self, is_successful: bool) {
         let search_entry = &self.imp().search_entry;
 
         search_entry.remove_css_class("success");
@@ -143,35 +164,71 @@ impl SidebarSearchRow {
         }
     }
 
+    pub fn set_busy(&self, busy: bool) {
+        let spinner = &self.imp().spinner;
+        spinner.set_visible(busy);
+        spinner.set_spinning(busy);
+    }
+
     pub fn state(&self) -> SidebarSearchRowState {
         self.imp().state.get()
     }
 
     pub fn set_state(&self, new_state: SidebarSearchRowState) -> bool {
         let current_state = self.imp().state.get();
-        let effective_state = match (new_state, self.is_empty()) {
-            (SidebarSearchRowState::Inactive, false) => return false,
-            (SidebarSearchRowState::Active, true) => {
-                self.imp().search_entry.grab_focus();
-                SidebarSearchRowState::Idle
-            },
-            (state, _) => state
-        };
+        let is_empty = self.is_empty();
+        if new_state == SidebarSearchRowState::Active && is_empty {
+            self.imp().search_entry.grab_focus();
+        }
 
-        if current_state == effective_state {
+        let Some(effective_state) = Self::state_transition(current_state, new_state, is_empty) else {
             return false;
-        }
+        };
 
         self.imp().state.set(effective_state);
         self.emit_by_name::<()>(STATE_CHANGED_SIGNAL, &[&effective_state]);
         true
     }
 
+    /// The state to switch to, or `None` when nothing changes. A row with a
+    /// query stays active, and activating an empty row only focuses it, so it
+    /// stays idle until something is typed.
+    fn state_transition(
+        current_state: SidebarSearchRowState,
+        new_state: SidebarSearchRowState,
+        is_empty: bool
+    ) -> Option<SidebarSearchRowState> {
+        let effective_state = match (new_state, is_empty) {
+            (SidebarSearchRowState::Inactive, false) => return None,
+            (SidebarSearchRowState::Active, true) => SidebarSearchRowState::Idle,
+            (state, _) => state
+        };
+
+        (effective_state != current_state).then_some(effective_state)
+    }
+
     pub fn clear_search(&self) {
         self.imp().search_entry.set_text("");
         self.set_state(SidebarSearchRowState::Idle);
     }
 
+    /// Replaces the query as if it had been typed. The entry's `search-changed`
+    /// emission goes through the same `had_content` guard, so clearing an
+    /// already empty entry does not trigger a search.
+    pub fn set_search_text(&self, text: &str) {
+        self.imp().search_entry.set_text(text);
+        self.set_state(Self::requested_state_for_text(text));
+    }
+
+    /// An empty text clears the search, anything else activates the row.
+    const fn requested_state_for_text(text: &str) -> SidebarSearchRowState {
+        if text.is_empty() {
+            SidebarSearchRowState::Idle
+        } else {
+            SidebarSearchRowState::Active
+        }
+    }
+
     pub fn search_text(&self) -> GString {
         self.imp().search_entry.text()
     }
@@ -180,9 +237,11 @@ impl SidebarSearchRow {
         self.imp().search_entry.text().is_empty()
     }
 
+    /// The callback reports the outcome through `set_successful_search` once
+    /// results have been applied, which may happen after it returns.
     pub fn connect_search_changed<F>(&self, callback: F) -> glib::SignalHandlerId
     where
-        F: Fn(&Self, &str) -> bool + 'static,
+        F: Fn(&Self, &str) + 'static,
     {
         let this_weak = self.downgrade();
         self.imp().search_entry.connect_search_changed(move |entry| {
@@ -192,8 +251,19 @@ impl SidebarSearchRow {
                 && (this.imp().had_content.get() || has_content)
             {
                 this.imp().had_content.set(has_content);
-                let is_successful = callback(&this, &entry.text());
-                this.set_successful_search(is_successful);
+                callback(&this, &entry.text());
+            }
+        })
+    }
+
+    pub fn connect_text_changed<F>(&self, callback: F) -> glib::SignalHandlerId
+    where
+        F: Fn(&Self, &str) + 'static,
+    {
+        let this_weak = self.downgrade();
+        self.imp().search_entry.connect_search_changed(move |entry| {
+            if let Some(this) = this_weak.upgrade() {
+                callback(&this, &entry.text());
             }
         })
     }
@@ -209,4 +279,41 @@ impl SidebarSearchRow {
             None
         })
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::SidebarSearchRowState::{Active, Idle, Inactive};
+
+    #[test]
+    fn clearing_returns_the_row_to_idle() {
+        assert_eq!(SidebarSearchRow::state_transition(Active, Idle, true), Some(Idle));
+        assert_eq!(SidebarSearchRow::state_transition(Inactive, Idle, true), Some(Idle));
+        assert_eq!(SidebarSearchRow::state_transition(Idle, Idle, true), None);
+    }
+
+    #[test]
+    fn row_with_a_query_cannot_be_deactivated() {
+        assert_eq!(SidebarSearchRow::state_transition(Active, Inactive, false), None);
+        assert_eq!(SidebarSearchRow::state_transition(Active, Inactive, true), Some(Inactive));
+    }
+
+    fn state_after_setting(current_state: SidebarSearchRowState, text: &str) -> Option<SidebarSearchRowState> {
+        let requested_state = SidebarSearchRow::requested_state_for_text(text);
+        SidebarSearchRow::state_transition(current_state, requested_state, text.is_empty())
+    }
+
+    #[test]
+    fn setting_a_query_activates_the_row() {
+        assert_eq!(state_after_setting(Idle, "mail"), Some(Active));
+        assert_eq!(state_after_setting(Inactive, "mail"), Some(Active));
+        assert_eq!(state_after_setting(Active, "mail"), None);
+    }
+
+    #[test]
+    fn setting_an_empty_query_returns_the_row_to_idle() {
+        assert_eq!(state_after_setting(Active, ""), Some(Idle));
+        assert_eq!(state_after_setting(Idle, ""), None);
+    }
 }
\ No newline at end of file
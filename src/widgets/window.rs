@@ -4,11 +4,13 @@ use super::navigation::Navigation;
 
 use gtk::gio::{ActionGroup, ActionMap};
 use std::sync::OnceLock;
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
+use std::time::{Duration, Instant};
 
-const WINDOW_SIZE_CHANGED_SIGNAL: &str = "state-changed";
+const WINDOW_SIZE_CHANGED_SIGNAL: &str = "size-changed";
+const TOAST_DEDUP_WINDOW: Duration = Duration::from_secs(3);
 
-#[derive(Default, Clone, Copy, Debug, glib::Boxed)]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, glib::Boxed)]
 #[boxed_type(name = "WindowSize")]
 pub struct WindowSize {
     pub width: u32,
@@ -16,6 +18,17 @@ pub struct WindowSize {
     pub maximized: bool
 }
 
+impl WindowSize {
+    /// `None` until the window has a default size of its own.
+    fn from_default_size(width: i32, height: i32, maximized: bool) -> Option<Self> {
+        (width > 0 && height > 0).then(|| Self {
+            width: width.unsigned_abs(),
+            height: height.unsigned_abs(),
+            maximized,
+        })
+    }
+}
+
 mod imp {
     use super::*;
 
@@ -32,6 +45,7 @@ mod imp {
         pub breakpoint: TemplateChild<adw::Breakpoint>,
 
         pub size: Cell<WindowSize>,
+        pub last_toast: RefCell<Option<(String, Instant)>>,
     }
 
     #[glib::object_subclass]
@@ -124,21 +138,12 @@ impl Window {
     fn update_window_size(&self) {
         let (width, height) = self.default_size();
 
-        if width > 0 && height > 0 {
-            let mut size = self.imp().size.get();
-            size.width = width.unsigned_abs();
-            size.height = height.unsigned_abs();
-            size.maximized = self.is_maximized();
-
-            let previous_size = self.imp().size.get();
-
-            if size.width != previous_size.width
-                || size.height != previous_size.height
-                || size.maximized != previous_size.maximized
-            {
-                self.imp().size.set(size);
-                self.emit_by_name::<()>(WINDOW_SIZE_CHANGED_SIGNAL, &[&size]);
-            }
+        if
+            let Some(size) = WindowSize::from_default_size(width, height, self.is_maximized())
+            && size != self.imp().size.get()
+        {
+            self.imp().size.set(size);
+            self.emit_by_name::<()>(WINDOW_SIZE_CHANGED_SIGNAL, &[&size]);
         }
     }
 
@@ -188,16 +193,75 @@ impl Window {
     }
 
     pub fn notify(&self, message: &str) {
+        self.notify_with_timeout(message, None);
+    }
+
+    /// `timeout` is in seconds, with `None` keeping the toast default. A message
+    /// identical to the previous one is dropped while that one is still recent.
+    pub fn notify_with_timeout(&self, message: &str, timeout: Option<u32>) {
+        if !self.record_toast(message) {
+            return;
+        }
+
         let toast = adw::Toast::new(message);
+        if let Some(timeout) = timeout {
+            toast.set_timeout(timeout);
+        }
         self.imp().toast_overlay.add_toast(toast);
     }
 
+    /// Remembers `message` as the latest toast, or returns false when it
+    /// duplicates a recent one and should not be shown.
+    fn record_toast(&self, message: &str) -> bool {
+        let now = Instant::now();
+        let mut last_toast = self.imp().last_toast.borrow_mut();
+        let last = last_toast.as_ref().map(|(last_message, shown_at)| (last_message.as_str(), *shown_at));
+        if Self::is_duplicate_toast(last, message, now) {
+            return false;
+        }
+        *last_toast = Some((message.to_string(), now));
+        true
+    }
+
+    fn is_duplicate_toast(last: Option<(&str, Instant)>, message: &str, now: Instant) -> bool {
+        last.is_some_and(|(last_message, shown_at)| {
+            last_message == message && now.saturating_duration_since(shown_at) < TOAST_DEDUP_WINDOW
+        })
+    }
+
+    pub fn notify_with_action<F>(&self, message: &str, label: &str, callback: F)
+    where
+        F: Fn() + 'static,
+    {
+        if !self.record_toast(message) {
+            return;
+        }
+
+        self.imp().toast_overlay.add_toast(Self::action_toast(message, label, callback));
+    }
+
+    fn action_toast<F>(message: &str, label: &str, callback: F) -> adw::Toast
+    where
+        F: Fn() + 'static,
+    {
+        let toast = adw::Toast::builder()
+            .title(message)
+            .button_label(label)
+            .build();
+        toast.connect_button_clicked(move |_| callback());
+        toast
+    }
+
     pub fn set_size(&self, size: WindowSize) {
         self.imp().size.set(size);
         self.set_default_size(size.width.cast_signed(), size.height.cast_signed());
         self.set_maximized(size.maximized);
     }
 
+    pub fn current_size(&self) -> WindowSize {
+        self.imp().size.get()
+    }
+
     pub fn connect_size_changed<F>(&self, callback: F) -> glib::SignalHandlerId
     where
         F: Fn(&Self, WindowSize) + 'static,
@@ -209,4 +273,82 @@ impl Window {
             None
         })
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_gtk;
+    use std::rc::Rc;
+
+    #[test]
+    fn resizing_emits_size_changed_once_per_change() {
+        let received = test_gtk::run(|| {
+            let window = glib::Object::builder::<Window>().build();
+            let received = Rc::new(RefCell::new(Vec::new()));
+
+            let received_sizes = received.clone();
+            window.connect_size_changed(move |_, size| received_sizes.borrow_mut().push(size));
+            window.set_default_size(800, 600);
+            window.set_default_size(800, 600);
+
+            let received = received.borrow().clone();
+            (received, window.current_size())
+        });
+
+        if let Some((received, current_size)) = received {
+            let size = WindowSize { width: 800, height: 600, maximized: false };
+            assert_eq!(received, [size]);
+            assert_eq!(current_size, size);
+        }
+    }
+
+    #[test]
+    fn default_size_needs_both_dimensions() {
+        assert_eq!(
+            WindowSize::from_default_size(1000, 600, true),
+            Some(WindowSize { width: 1000, height: 600, maximized: true })
+        );
+        assert_eq!(WindowSize::from_default_size(0, 600, false), None);
+        assert_eq!(WindowSize::from_default_size(1000, -1, false), None);
+    }
+
+    #[test]
+    fn maximizing_changes_the_size() {
+        let size = WindowSize { width: 1000, height: 600, maximized: false };
+        assert_ne!(size, WindowSize { maximized: true, ..size });
+    }
+
+    #[test]
+    fn repeated_message_is_a_duplicate_only_within_the_window() {
+        let shown_at = Instant::now();
+        let last = Some(("Offline", shown_at));
+        assert!(Window::is_duplicate_toast(last, "Offline", shown_at + Duration::from_secs(1)));
+        assert!(!Window::is_duplicate_toast(last, "Offline", shown_at + TOAST_DEDUP_WINDOW));
+    }
+
+    #[test]
+    fn different_or_first_message_is_not_a_duplicate() {
+        let shown_at = Instant::now();
+        assert!(!Window::is_duplicate_toast(Some(("Offline", shown_at)), "Retrying", shown_at));
+        assert!(!Window::is_duplicate_toast(None, "Offline", shown_at));
+    }
+
+    #[test]
+    fn action_toasts_carry_the_button_label_and_callback() {
+        let toast = test_gtk::run(|| {
+            let clicked = Rc::new(Cell::new(false));
+            let clicked_flag = clicked.clone();
+            let toast = Window::action_toast("Link copied", "Undo", move || clicked_flag.set(true));
+            toast.emit_by_name::<()>("button-clicked", &[]);
+
+            (toast.title().map(|title| title.to_string()), toast.button_label().map(|label| label.to_string()), clicked.get())
+        });
+
+        if let Some((title, button_label, clicked)) = toast {
+            assert_eq!(title.as_deref(), Some("Link copied"));
+            assert_eq!(button_label.as_deref(), Some("Undo"));
+            assert!(clicked);
+        }
+    }
+}